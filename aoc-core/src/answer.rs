@@ -0,0 +1,158 @@
+use std::{
+    any::type_name,
+    fmt::{self, Display, Formatter},
+};
+
+use eyre::{eyre, Report, Result};
+use num::{BigInt, CheckedAdd, CheckedMul, Integer, One, Zero};
+
+/// The answer to one part of a puzzle, in whichever form the solution worked
+/// it out.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    /// A number that does not fit in any of the primitive integers.
+    Big(BigInt),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(answer) => write!(f, "{answer}"),
+            Answer::Unsigned(answer) => write!(f, "{answer}"),
+            Answer::Big(answer) => write!(f, "{answer}"),
+            Answer::Text(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident: $($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(answer: $integer) -> Self {
+                    // Every primitive integer fits in a 128 bit one of the
+                    // same signedness.
+                    Answer::$variant(answer as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(Signed: i8, i16, i32, i64, i128, isize);
+impl_from_integer!(Unsigned: u8, u16, u32, u64, u128, usize);
+
+impl From<BigInt> for Answer {
+    fn from(answer: BigInt) -> Self {
+        Answer::Big(answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::Text(answer.to_string())
+    }
+}
+
+/// Sums and products that are an error when they overflow, instead of
+/// panicking in debug builds and wrapping around in release builds.
+pub trait CheckedIterator: Iterator + Sized {
+    fn checked_sum(mut self) -> Result<Self::Item>
+    where
+        Self::Item: CheckedAdd + Zero,
+    {
+        self.try_fold(Self::Item::zero(), |sum, item| {
+            sum.checked_add(&item)
+                .ok_or_else(|| overflow::<Self::Item>("sum"))
+        })
+    }
+
+    fn checked_product(mut self) -> Result<Self::Item>
+    where
+        Self::Item: CheckedMul + One,
+    {
+        self.try_fold(Self::Item::one(), |product, item| {
+            product
+                .checked_mul(&item)
+                .ok_or_else(|| overflow::<Self::Item>("product"))
+        })
+    }
+}
+
+impl<I: Iterator> CheckedIterator for I {}
+
+/// Converts `value` to another integer type, failing if it does not fit.
+pub fn checked_cast<T, U>(value: T) -> Result<U>
+where
+    T: Copy + Display,
+    U: TryFrom<T>,
+{
+    U::try_from(value).map_err(|_| eyre!("{value} does not fit in a {}", type_name::<U>()))
+}
+
+/// The least common multiple of `a` and `b`, failing if it does not fit.
+pub fn checked_lcm<T: Integer + CheckedMul>(a: T, b: T) -> Result<T> {
+    if a.is_zero() || b.is_zero() {
+        return Ok(T::zero());
+    }
+    let gcd = a.gcd(&b);
+    (a / gcd)
+        .checked_mul(&b)
+        .ok_or_else(|| overflow::<T>("least common multiple"))
+}
+
+fn overflow<T>(operation: &str) -> Report {
+    eyre!("the {operation} does not fit in a {}", type_name::<T>())
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::answer::{checked_cast, checked_lcm, Answer, CheckedIterator};
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(54601u32).to_string(), "54601");
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(
+            Answer::from(BigInt::from(u128::MAX) * 2).to_string(),
+            "680564733841876926926749214863536422910"
+        );
+        assert_eq!(Answer::from("AAA").to_string(), "AAA");
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!([1u8, 2, 3].into_iter().checked_sum().unwrap(), 6);
+        assert_eq!(
+            [200u8, 100]
+                .into_iter()
+                .checked_sum()
+                .unwrap_err()
+                .to_string(),
+            "the sum does not fit in a u8"
+        );
+        assert_eq!([2u8, 3, 4].into_iter().checked_product().unwrap(), 24);
+        assert!([16u8, 16].into_iter().checked_product().is_err());
+
+        assert_eq!(checked_cast::<usize, u32>(7).unwrap(), 7);
+        assert_eq!(
+            checked_cast::<u64, u32>(1 << 32).unwrap_err().to_string(),
+            "4294967296 does not fit in a u32"
+        );
+
+        assert_eq!(checked_lcm(4u64, 6).unwrap(), 12);
+        assert!(checked_lcm(u64::MAX, u64::MAX - 1).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::{root_directory, InputSource},
+    Part,
+};
+
+/// The answers that are known to be correct, by day, input and part, so that
+/// changes to a solution can be checked against them.
+///
+/// They are kept in `answers.toml` at the root directory, which looks like
+/// this:
+///
+/// ```toml
+/// [day-1.real]
+/// part1 = "54601"
+/// part2 = "54078"
+/// part2_wrong = ["54079"]
+/// ```
+///
+/// The answers that were submitted and turned out to be wrong are kept too,
+/// so that they are not submitted again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1_wrong: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2_wrong: Vec<String>,
+}
+
+impl PartAnswers {
+    fn get_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+
+    fn wrong(&self, part: Part) -> &[String] {
+        match part {
+            Part::One => &self.part1_wrong,
+            Part::Two => &self.part2_wrong,
+        }
+    }
+
+    fn wrong_mut(&mut self, part: Part) -> &mut Vec<String> {
+        match part {
+            Part::One => &mut self.part1_wrong,
+            Part::Two => &mut self.part2_wrong,
+        }
+    }
+}
+
+/// How an answer compares to the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// There is no known answer to compare it to.
+    Unknown,
+}
+
+impl Answers {
+    /// The file the answers are kept in.
+    pub fn path() -> PathBuf {
+        root_directory().join("answers.toml")
+    }
+
+    /// Reads the answers from [`Answers::path`], or returns no answers if the
+    /// file does not exist yet.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::path())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let answers = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&answers).wrap_err_with(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let answers = toml::to_string(self)?;
+        fs::write(path, answers).wrap_err_with(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, source: &InputSource, part: Part) -> Option<&str> {
+        let answers = self.days.get(&day_key(day))?.get(&source.to_string())?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    /// Records `answer` as the correct one, replacing whatever was known.
+    /// Answers for stdin cannot be recorded, because there is no telling
+    /// what will be piped in next time.
+    pub fn record(
+        &mut self,
+        day: u8,
+        source: &InputSource,
+        part: Part,
+        answer: &str,
+    ) -> Result<()> {
+        *self.entry(day, source)?.get_mut(part) = Some(answer.to_string());
+        Ok(())
+    }
+
+    /// Records that `answer` was submitted and is not the correct one.
+    pub fn record_wrong(
+        &mut self,
+        day: u8,
+        source: &InputSource,
+        part: Part,
+        answer: &str,
+    ) -> Result<()> {
+        let wrong = self.entry(day, source)?.wrong_mut(part);
+        if !wrong.iter().any(|wrong| wrong == answer) {
+            wrong.push(answer.to_string());
+        }
+        Ok(())
+    }
+
+    /// Whether `answer` was submitted before and turned out to be wrong.
+    pub fn is_wrong(&self, day: u8, source: &InputSource, part: Part, answer: &str) -> bool {
+        self.days
+            .get(&day_key(day))
+            .and_then(|inputs| inputs.get(&source.to_string()))
+            .is_some_and(|answers| answers.wrong(part).iter().any(|wrong| wrong == answer))
+    }
+
+    fn entry(&mut self, day: u8, source: &InputSource) -> Result<&mut PartAnswers> {
+        if *source == InputSource::Stdin {
+            return Err(eyre!(
+                "answers for input read from stdin cannot be recorded"
+            ));
+        }
+
+        Ok(self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(source.to_string())
+            .or_default())
+    }
+
+    pub fn check(&self, day: u8, source: &InputSource, part: Part, answer: &str) -> Check {
+        match self.get(day, source, part) {
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Incorrect {
+                expected: expected.to_string(),
+            },
+            None => Check::Unknown,
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day-{day}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::{Answers, Check},
+        input::InputSource,
+        Part,
+    };
+
+    #[test]
+    fn record_and_check() {
+        let mut answers: Answers = toml::from_str("[day-1.real]\npart1 = \"54601\"\n").unwrap();
+
+        assert_eq!(
+            answers.check(1, &InputSource::Real, Part::One, "54601"),
+            Check::Correct
+        );
+        assert_eq!(
+            answers.check(1, &InputSource::Real, Part::One, "54600"),
+            Check::Incorrect {
+                expected: "54601".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(1, &InputSource::Real, Part::Two, "54078"),
+            Check::Unknown
+        );
+        assert_eq!(
+            answers.check(1, &InputSource::Example1, Part::One, "54601"),
+            Check::Unknown
+        );
+
+        answers
+            .record(1, &InputSource::Real, Part::Two, "54078")
+            .unwrap();
+        answers
+            .record(2, &InputSource::Example1, Part::One, "8")
+            .unwrap();
+        assert!(answers
+            .record(2, &InputSource::Stdin, Part::One, "8")
+            .is_err());
+        assert_eq!(
+            toml::to_string(&answers).unwrap(),
+            "[day-1.real]\npart1 = \"54601\"\npart2 = \"54078\"\n\n\
+             [day-2.example1]\npart1 = \"8\"\n"
+        );
+    }
+
+    #[test]
+    fn wrong_answers() {
+        let mut answers = Answers::default();
+        for answer in ["100", "90", "100"] {
+            answers
+                .record_wrong(3, &InputSource::Real, Part::Two, answer)
+                .unwrap();
+        }
+
+        assert!(answers.is_wrong(3, &InputSource::Real, Part::Two, "90"));
+        assert!(!answers.is_wrong(3, &InputSource::Real, Part::Two, "95"));
+        assert!(!answers.is_wrong(3, &InputSource::Real, Part::One, "90"));
+        assert_eq!(
+            toml::to_string(&answers).unwrap(),
+            "[day-3.real]\npart2_wrong = [\"100\", \"90\"]\n"
+        );
+        assert_eq!(
+            toml::from_str::<Answers>(&toml::to_string(&answers).unwrap()).unwrap(),
+            answers
+        );
+    }
+}
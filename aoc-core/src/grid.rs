@@ -0,0 +1,357 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use eyre::{eyre, Result};
+use nom::multi::many1;
+
+use crate::parsing::{lines, mapped_char, ParseError, ParseErrorKind, ParseResult};
+
+/// A position in a [`Grid`], as `(x, y)` with `(0, 0)` in the top left.
+pub type Position = (usize, usize);
+
+/// The steps to the neighbors above, to the right, below and to the left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to all eight neighbors, clockwise from the top left.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid of cells, such as a puzzle's character map.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be as long as each other.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(eyre!(
+                "row {y} has {} cells, but the first row has {width}",
+                row.len()
+            ));
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(&mut cell)
+                .collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let index = self.index(position);
+        self.contains(position).then(|| &mut self.cells[index])
+    }
+
+    fn index(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+
+    /// Takes a step of `(dx, dy)` from `position`, unless that leaves the
+    /// grid.
+    pub fn step(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The positions above, to the right of, below and to the left of
+    /// `position` that are inside the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_4
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions around `position`, including the diagonal ones, that
+    /// are inside the grid.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_8
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn position_of(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .iter()
+                .map(|(position, cell)| f(position, cell))
+                .collect(),
+        }
+    }
+
+    /// Flips the grid over its diagonal, so that the rows become the columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Inserts a row filled with `value` before row `y`, moving the rows
+    /// from `y` onwards down.
+    pub fn insert_row(&mut self, y: usize, value: T)
+    where
+        T: Clone,
+    {
+        assert!(y <= self.height, "row {y} is outside of the grid");
+        let index = y * self.width;
+        self.cells
+            .splice(index..index, std::iter::repeat_n(value, self.width));
+        self.height += 1;
+    }
+
+    /// Inserts a column filled with `value` before column `x`, moving the
+    /// columns from `x` onwards to the right.
+    pub fn insert_column(&mut self, x: usize, value: T)
+    where
+        T: Clone,
+    {
+        assert!(x <= self.width, "column {x} is outside of the grid");
+        for y in (0..self.height).rev() {
+            self.cells.insert(y * self.width + x, value.clone());
+        }
+        self.width += 1;
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{position:?} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("{position:?} is outside of the {width}x{height} grid"),
+        }
+    }
+}
+
+/// Draws the grid with one line per row, writing each cell with its own
+/// `Display`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a character map into a [`Grid`], turning each character into a
+/// cell with `cell`. Like [`lines`], it stops at a blank line or the end of
+/// the input.
+pub fn grid<'a, T, E: Display>(
+    mut cell: impl FnMut(char) -> Result<T, E>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Grid<T>> {
+    move |input: &'a str| {
+        // Each row is kept along with the input from its start, to point at
+        // it if it is too short or too long.
+        let row = |input: &'a str| {
+            let (rest, cells) = many1(mapped_char(&mut cell))(input)?;
+            Ok((rest, (input, cells)))
+        };
+        let (rest, rows) = lines(row)(input)?;
+
+        let width = rows[0].1.len();
+        if let Some((row, cells)) = rows.iter().find(|(_, cells)| cells.len() != width) {
+            let message = format!(
+                "this row has {} cells, but the first row has {width}",
+                cells.len()
+            );
+            return Err(nom::Err::Failure(ParseError {
+                errors: vec![(*row, ParseErrorKind::External(message))],
+            }));
+        }
+
+        let rows = rows.into_iter().map(|(_, cells)| cells).collect();
+        let grid = Grid::from_rows(rows).expect("the rows are all as long as each other");
+        Ok((rest, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{grid, Grid},
+        parsing::{finish, ParseMode},
+    };
+
+    fn digits(input: &str) -> Grid<u32> {
+        let result = grid(|c: char| c.to_digit(10).ok_or("not a digit"))(input);
+        finish(input, result, ParseMode::Strict).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let input = "123\n45\n678";
+        let result = super::grid(|c: char| c.to_digit(10).ok_or("not a digit"))(input);
+        assert_eq!(
+            finish(input, result, ParseMode::Strict)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: invalid value: this row has 2 cells, but the first row has 3\n  \
+             |\n2 | 45\n  | ^"
+        );
+
+        let input = "123\n4x6";
+        let result = super::grid(|c: char| c.to_digit(10).ok_or("not a digit"))(input);
+        assert_eq!(
+            finish(input, result, ParseMode::Strict)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: invalid value: not a digit\n  |\n2 | 4x6\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits("123\n456\n789");
+
+        let neighbors = |position| {
+            grid.neighbors4(position)
+                .map(|p| grid[p])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(neighbors((1, 1)), vec![2, 6, 8, 4]);
+        assert_eq!(neighbors((0, 0)), vec![2, 4]);
+
+        let neighbors = |position| {
+            grid.neighbors8(position)
+                .map(|p| grid[p])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(neighbors((1, 1)), vec![1, 2, 3, 6, 9, 8, 7, 4]);
+        assert_eq!(neighbors((2, 2)), vec![5, 6, 8]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid = digits("123\n456");
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.find(|&cell| cell > 4), Some((1, 1)));
+        assert_eq!(grid.position_of(&7), None);
+
+        grid.insert_row(1, 0);
+        grid.insert_column(3, 9);
+        grid.insert_column(0, 9);
+        assert_eq!(grid.to_string(), "91239\n90009\n94569");
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use eyre::{eyre, Result, WrapErr};
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    /// The personal puzzle input, `real-input.txt`.
+    Real,
+    /// The first example from the puzzle description, `test-input.txt`.
+    Example1,
+    /// The second example from the puzzle description, `test-input-2.txt`.
+    Example2,
+    /// Any other file.
+    Path(PathBuf),
+    /// Whatever is piped into the process.
+    Stdin,
+}
+
+impl InputSource {
+    /// The file this input is read from, if it is read from a file.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        let file_name = match self {
+            InputSource::Real => "real-input.txt",
+            InputSource::Example1 => "test-input.txt",
+            InputSource::Example2 => "test-input-2.txt",
+            InputSource::Path(path) => return Some(path.clone()),
+            InputSource::Stdin => return None,
+        };
+
+        Some(day_directory(day).join(file_name))
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "real" => InputSource::Real,
+            "example" | "example1" => InputSource::Example1,
+            "example2" => InputSource::Example2,
+            "-" => InputSource::Stdin,
+            "" => return Err(eyre!("the input cannot be empty")),
+            path => InputSource::Path(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Real => write!(f, "real"),
+            InputSource::Example1 => write!(f, "example1"),
+            InputSource::Example2 => write!(f, "example2"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "-"),
+        }
+    }
+}
+
+/// The directory that holds all of the `day-N` directories. This is the
+/// workspace root unless `AOC_ROOT` says otherwise.
+pub fn root_directory() -> PathBuf {
+    match env::var_os("AOC_ROOT") {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc-core is inside the workspace")
+            .to_path_buf(),
+    }
+}
+
+pub fn day_directory(day: u8) -> PathBuf {
+    root_directory().join(format!("day-{day}"))
+}
+
+/// Reads a day's puzzle input from `source`.
+pub fn load(day: u8, source: &InputSource) -> Result<String> {
+    String::from_utf8(load_bytes(day, source)?).map_err(|error| {
+        eyre!(
+            "the {source} input of day {day} is not valid UTF-8: {}",
+            error.utf8_error()
+        )
+    })
+}
+
+/// Reads a day's puzzle input from `source` as it is, for the days that can
+/// make sense of input that is not valid UTF-8.
+pub fn load_bytes(day: u8, source: &InputSource) -> Result<Vec<u8>> {
+    let Some(path) = source.path(day) else {
+        let mut input = Vec::new();
+        io::stdin()
+            .read_to_end(&mut input)
+            .wrap_err("failed to read input from stdin")?;
+        return Ok(input);
+    };
+
+    if !path.exists() {
+        return Err(match source {
+            InputSource::Path(_) => eyre!("input file {} does not exist", path.display()),
+            _ => eyre!(
+                "day {day} has no {source} input, expected it at {}",
+                path.display()
+            ),
+        });
+    }
+
+    fs::read(&path).wrap_err_with(|| format!("failed to read {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::input::{load, InputSource};
+
+    #[test]
+    fn parse_source() {
+        assert_eq!("real".parse::<InputSource>().unwrap(), InputSource::Real);
+        assert_eq!(
+            "example".parse::<InputSource>().unwrap(),
+            InputSource::Example1
+        );
+        assert_eq!(
+            "example2".parse::<InputSource>().unwrap(),
+            InputSource::Example2
+        );
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "day-1/other.txt".parse::<InputSource>().unwrap(),
+            InputSource::Path(PathBuf::from("day-1/other.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_input() {
+        let error = load(25, &InputSource::Example2).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("day 25 has no example2 input"));
+    }
+}
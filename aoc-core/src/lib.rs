@@ -0,0 +1,206 @@
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    str,
+    time::{Duration, Instant},
+};
+
+use answer::Answer;
+use eyre::{bail, eyre, Result, WrapErr};
+use input::InputSource;
+use parsing::{ParseMode, ParseResult};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+pub mod answer;
+pub mod answers;
+pub mod grid;
+pub mod input;
+pub mod parsing;
+
+/// The shape every day's puzzle solution follows, so that tooling can treat
+/// all of the days the same way.
+pub trait Solution {
+    /// The day of the puzzle, starting at 1.
+    const DAY: u8;
+
+    type Input;
+    type Output: Into<Answer> + Debug + PartialEq;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Self::Output;
+    const PART_2_EXPECTED_TEST_OUTPUT: Self::Output;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Self::Input>;
+
+    /// Runs [`Solution::parse_input`] over the whole input, with its line
+    /// endings and trailing whitespace normalized, describing where the input
+    /// is malformed if it does not parse.
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input> {
+        let input = parsing::normalize_input(input);
+        parsing::finish(&input, Self::parse_input(&input), mode)
+    }
+
+    /// Parses an input that may not be valid UTF-8. Most days cannot make
+    /// sense of that, so unless a day says otherwise it is an error.
+    fn parse_bytes(input: &[u8], mode: ParseMode) -> Result<Self::Input> {
+        let input = str::from_utf8(input)
+            .map_err(|error| eyre!("the input is not valid UTF-8: {error}"))?;
+        Self::parse(input, mode)
+    }
+
+    fn solve_part1(input: Self::Input) -> Result<Self::Output>;
+
+    fn solve_part2(input: Self::Input) -> Result<Self::Output>;
+}
+
+/// Makes up random inputs for a day's puzzle, for testing the solution with
+/// more than the examples and the real input.
+pub trait Generate: Solution {
+    /// Makes up an input that parses, and that the puzzle describes. `size`
+    /// is how big it is in whatever the puzzle has a list of, like lines,
+    /// hands or the side of a grid.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String;
+}
+
+/// The error a part returns until it has been solved, so that the tests can
+/// tell it apart from a part that fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "this part has not been solved yet")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+/// Which half of a day's puzzle to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = eyre::Report;
+
+    fn try_from(part: u8) -> Result<Self> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(eyre!("there is no part {part}, only parts 1 and 2")),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A day's [`Solution`] with its input and output types erased, so that all
+/// of the days can be kept in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    solve: fn(Part, &[u8], ParseMode) -> Result<Run>,
+    generate: fn(&mut StdRng, usize) -> String,
+}
+
+/// The answer to one part of a puzzle, along with how long it took to get it.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Day {
+    pub fn new<S: Generate>() -> Self {
+        Day {
+            day: S::DAY,
+            solve: solve::<S>,
+            generate: S::generate::<StdRng>,
+        }
+    }
+
+    /// Parses `input` and solves one part of the puzzle with it, returning
+    /// the answer and how long parsing and solving took.
+    pub fn solve(&self, part: Part, input: &str, mode: ParseMode) -> Result<Run> {
+        self.solve_bytes(part, input.as_bytes(), mode)
+    }
+
+    /// Like [`Day::solve`], but for an input that may not be valid UTF-8.
+    pub fn solve_bytes(&self, part: Part, input: &[u8], mode: ParseMode) -> Result<Run> {
+        (self.solve)(part, input, mode)
+    }
+
+    /// Makes up an input of about `size`, which is always the same for the
+    /// same `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+fn solve<S: Solution>(part: Part, input: &[u8], mode: ParseMode) -> Result<Run> {
+    let start = Instant::now();
+    let input = S::parse_bytes(input, mode).wrap_err("failed to parse input")?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let output = match part {
+        Part::One => S::solve_part1(input)?,
+        Part::Two => S::solve_part2(input)?,
+    };
+    let solve_time = start.elapsed();
+
+    Ok(Run {
+        answer: output.into(),
+        parse_time,
+        solve_time,
+    })
+}
+
+/// Solves both parts of a day's puzzle with their examples, and checks the
+/// answers against the expected test outputs. Unsolved parts are skipped.
+pub fn check_examples<S: Solution>() -> Result<()> {
+    Part::ALL.into_iter().try_for_each(check_example::<S>)
+}
+
+/// Like [`check_examples`], but for only one part. Part 1 is solved with
+/// `test-input.txt` and part 2 with `test-input-2.txt`.
+pub fn check_example<S: Solution>(part: Part) -> Result<()> {
+    let source = match part {
+        Part::One => InputSource::Example1,
+        Part::Two => InputSource::Example2,
+    };
+    let input = input::load(S::DAY, &source)?;
+    let input = S::parse(&input, ParseMode::Strict)
+        .wrap_err_with(|| format!("failed to parse {source}"))?;
+    let (output, expected) = match part {
+        Part::One => (S::solve_part1(input), S::PART_1_EXPECTED_TEST_OUTPUT),
+        Part::Two => (S::solve_part2(input), S::PART_2_EXPECTED_TEST_OUTPUT),
+    };
+    match output {
+        Ok(output) if output == expected => Ok(()),
+        Ok(output) => bail!("part {part} gives {output:?} for {source} instead of {expected:?}"),
+        Err(error) if error.is::<Unsolved>() => Ok(()),
+        Err(error) => Err(error.wrap_err(format!("part {part} fails {source}"))),
+    }
+}
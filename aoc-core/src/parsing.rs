@@ -0,0 +1,487 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use eyre::{eyre, Report, Result};
+use nom::{
+    character::complete::{char, digit1, none_of, space0, space1},
+    combinator::{map_res, opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError},
+    multi::separated_list1,
+    sequence::{pair, preceded},
+    IResult,
+};
+
+pub type ParseResult<'a, T> = IResult<&'a str, T, ParseError<'a>>;
+
+/// A parse error that remembers where in the input it happened and why, so
+/// that it can be turned into a readable report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError<'a> {
+    /// The errors from the innermost parser outwards, along with the input
+    /// that was left when each of them failed.
+    pub errors: Vec<(&'a str, ParseErrorKind)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// A literal piece of text was expected.
+    Expected(&'static str),
+    Char(char),
+    Context(&'static str),
+    /// A value was parsed, but converting it failed with this message.
+    External(String),
+    Nom(ErrorKind),
+}
+
+impl<'a> ParseError<'a> {
+    pub fn expected(input: &'a str, expected: &'static str) -> Self {
+        ParseError {
+            errors: vec![(input, ParseErrorKind::Expected(expected))],
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for ParseError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        ParseError {
+            errors: vec![(input, ParseErrorKind::Nom(kind))],
+        }
+    }
+
+    fn append(input: &'a str, kind: ErrorKind, mut other: Self) -> Self {
+        other.errors.push((input, ParseErrorKind::Nom(kind)));
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        ParseError {
+            errors: vec![(input, ParseErrorKind::Char(c))],
+        }
+    }
+
+    /// Keeps whichever error got further into the input, since that is
+    /// usually the alternative that was meant.
+    fn or(self, other: Self) -> Self {
+        if other.errors[0].0.len() <= self.errors[0].0.len() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for ParseError<'a> {
+    fn add_context(input: &'a str, context: &'static str, mut other: Self) -> Self {
+        other.errors.push((input, ParseErrorKind::Context(context)));
+        other
+    }
+}
+
+impl<'a, E: Display> FromExternalError<&'a str, E> for ParseError<'a> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, error: E) -> Self {
+        ParseError {
+            errors: vec![(input, ParseErrorKind::External(error.to_string()))],
+        }
+    }
+}
+
+/// Matches `expected` exactly. Unlike `nom`'s `tag`, the error says which text
+/// was expected.
+pub fn tag<'a>(expected: &'static str) -> impl FnMut(&'a str) -> ParseResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((rest, &input[..expected.len()])),
+        None => Err(nom::Err::Error(ParseError::expected(input, expected))),
+    }
+}
+
+/// Parses one `item` per line, stopping at a blank line or the end of the
+/// input. Unlike `separated_list1(newline, item)`, a line that does not parse
+/// completely is an error instead of quietly being treated as the end of the
+/// list.
+pub fn lines<'a, T>(
+    mut item: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut items = Vec::new();
+
+        loop {
+            let (rest, value) = item(input).map_err(|error| match error {
+                nom::Err::Error(error) if !items.is_empty() => nom::Err::Failure(error),
+                error => error,
+            })?;
+            items.push(value);
+
+            match rest.strip_prefix('\n') {
+                Some(next) if !next.is_empty() && !next.starts_with('\n') => input = next,
+                _ if rest.is_empty() || rest.starts_with('\n') => return Ok((rest, items)),
+                _ => return Err(nom::Err::Failure(ParseError::expected(rest, "\n"))),
+            }
+        }
+    }
+}
+
+/// Parses `block`s separated by blank lines, stopping at the end of the input
+/// or at anything after a block that is not a blank line. Like [`lines`], a
+/// block that comes after a blank line but does not parse is an error.
+pub fn blocks<'a, T>(
+    mut block: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut blocks = Vec::new();
+
+        loop {
+            let (rest, value) = block(input).map_err(|error| match error {
+                nom::Err::Error(error) if !blocks.is_empty() => nom::Err::Failure(error),
+                error => error,
+            })?;
+            blocks.push(value);
+
+            match rest.strip_prefix("\n\n") {
+                Some(next) if !next.trim().is_empty() => input = next,
+                _ => return Ok((rest, blocks)),
+            }
+        }
+    }
+}
+
+/// Parses `value` after `label`, such as the numbers in `Time:   7  15   30`,
+/// skipping any spaces between them.
+pub fn labelled<'a, T>(
+    label: &'static str,
+    value: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    preceded(pair(tag(label), space0), value)
+}
+
+/// Parses a number without a sign.
+pub fn unsigned<'a, T>(input: &'a str) -> ParseResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parses a number that may start with a `-`.
+pub fn signed<'a, T>(input: &'a str) -> ParseResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Parses one or more `item`s on a line, separated by any number of spaces.
+/// Spaces before the first item are skipped.
+pub fn list<'a, T>(
+    item: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    preceded(space0, separated_list1(space1, item))
+}
+
+pub fn unsigned_list<'a, T>(input: &'a str) -> ParseResult<'a, Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    list(unsigned)(input)
+}
+
+pub fn signed_list<'a, T>(input: &'a str) -> ParseResult<'a, Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    list(signed)(input)
+}
+
+/// Parses one character other than a line ending and turns it into a `T`,
+/// such as a cell of a map or a card in a hand. A character that `cell`
+/// rejects is an error straight away, instead of letting another parser try.
+pub fn mapped_char<'a, T, E: Display>(
+    mut cell: impl FnMut(char) -> Result<T, E>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    move |input: &'a str| {
+        let (rest, c) = none_of("\r\n")(input)?;
+        match cell(c) {
+            Ok(cell) => Ok((rest, cell)),
+            Err(error) => Err(nom::Err::Failure(ParseError::from_external_error(
+                input,
+                ErrorKind::MapRes,
+                error,
+            ))),
+        }
+    }
+}
+
+/// Turns Windows line endings into `\n` and removes whitespace from the ends
+/// of lines and of the input, so that parsers only have to deal with one kind
+/// of line ending and never with trailing blank lines. Lines keep their
+/// numbers, so errors still point at the right line of the original input.
+pub fn normalize_input(input: &str) -> Cow<'_, str> {
+    let input = input.trim_end();
+    if !input.contains('\r')
+        && input
+            .lines()
+            .all(|line| line.len() == line.trim_end().len())
+    {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(
+            input
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+}
+
+/// What to do with input that is left over once the parser is done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Leftover input is an error.
+    Strict,
+    /// Leftover input is ignored.
+    #[default]
+    Lenient,
+}
+
+/// Turns the result of running a parser over `input` into either the parsed
+/// value or a report pointing at the line and column where parsing failed.
+pub fn finish<'a, T>(input: &'a str, result: ParseResult<'a, T>, mode: ParseMode) -> Result<T> {
+    match result {
+        Ok((rest, value)) => {
+            if mode == ParseMode::Strict && !rest.is_empty() {
+                return Err(report(
+                    input,
+                    rest,
+                    "expected the end of the input, found trailing input",
+                    &[],
+                ));
+            }
+            Ok(value)
+        }
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            let (rest, kind) = &error.errors[0];
+            let contexts = error
+                .errors
+                .iter()
+                .filter_map(|(_, kind)| match kind {
+                    ParseErrorKind::Context(context) => Some(*context),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            Err(report(input, rest, &describe(kind), &contexts))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(eyre!("the input ended unexpectedly")),
+    }
+}
+
+fn describe(kind: &ParseErrorKind) -> String {
+    match kind {
+        ParseErrorKind::Expected("\n") => "expected the end of the line".to_string(),
+        ParseErrorKind::Expected(expected) => format!("expected {expected:?}"),
+        ParseErrorKind::Char(c) => format!("expected {c:?}"),
+        ParseErrorKind::Context(context) => format!("expected {context}"),
+        ParseErrorKind::External(message) => format!("invalid value: {message}"),
+        ParseErrorKind::Nom(kind) => match kind {
+            ErrorKind::Digit => "expected a digit".to_string(),
+            ErrorKind::Alpha => "expected a letter".to_string(),
+            ErrorKind::AlphaNumeric => "expected a letter or a digit".to_string(),
+            ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace".to_string(),
+            ErrorKind::CrLf => "expected a line break".to_string(),
+            ErrorKind::Eof => "expected the end of the input".to_string(),
+            ErrorKind::Many1 | ErrorKind::SeparatedList => "expected at least one item".to_string(),
+            ErrorKind::Alt => "none of the alternatives matched".to_string(),
+            kind => format!("expected {}", kind.description().to_lowercase()),
+        },
+    }
+}
+
+/// Builds a report for an error that happened with `rest` left of `input`.
+fn report(input: &str, rest: &str, message: &str, contexts: &[&str]) -> Report {
+    let offset = input.len() - rest.len();
+    let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |index| offset + index);
+    let line_number = input[..offset].matches('\n').count() + 1;
+    let column = input[line_start..offset].chars().count() + 1;
+    let line = input[line_start..line_end].trim_end_matches('\r');
+
+    let gutter = " ".repeat(line_number.to_string().len());
+    let mut report = format!("line {line_number}, column {column}: {message}\n");
+    let _ = writeln!(report, "{gutter} |");
+    let _ = writeln!(report, "{line_number} | {line}");
+    let _ = write!(report, "{gutter} | {}^", " ".repeat(column - 1));
+    for context in contexts {
+        let _ = write!(report, "\n{gutter} = while parsing {context}");
+    }
+
+    eyre!(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{alpha1, digit1, newline},
+        combinator::map_res,
+        error::context,
+        multi::{count, separated_list1},
+        sequence::{preceded, separated_pair, terminated},
+    };
+
+    use crate::parsing::{
+        blocks, finish, labelled, lines, mapped_char, normalize_input, signed_list, tag, unsigned,
+        unsigned_list, ParseMode, ParseResult,
+    };
+
+    fn numbers(input: &str) -> ParseResult<'_, Vec<u8>> {
+        separated_list1(
+            newline,
+            context(
+                "a number",
+                preceded(tag("n = "), map_res(digit1, str::parse::<u8>)),
+            ),
+        )(input)
+    }
+
+    #[test]
+    fn expected_tag() {
+        let input = "n = 1\nn = 2\nm = 3";
+        let error = finish(input, numbers(input), ParseMode::Strict).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected the end of the input, found trailing input\n  \
+             |\n2 | n = 2\n  |      ^"
+        );
+
+        let input = "m = 1";
+        let error = finish(input, numbers(input), ParseMode::Lenient).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected \"n = \"\n  |\n1 | m = 1\n  | ^\n  = while parsing a number"
+        );
+    }
+
+    #[test]
+    fn invalid_value() {
+        let input = "n = 300\nn = 1";
+        let error = finish(input, numbers(input), ParseMode::Strict).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: invalid value: number too large to fit in target type\n  \
+             |\n1 | n = 300\n  |     ^\n  = while parsing a number"
+        );
+    }
+
+    #[test]
+    fn lenient_leftovers() {
+        let input = "n = 1\nn = 2\nm = 3";
+        assert_eq!(
+            finish(input, numbers(input), ParseMode::Lenient).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn malformed_line() {
+        let parse = |input| lines(preceded(tag("n = "), map_res(digit1, str::parse::<u8>)))(input);
+
+        let input = "n = 1\nn = 2\n\nm = 3";
+        assert_eq!(parse(input), Ok(("\n\nm = 3", vec![1, 2])));
+
+        let input = "n = 1\nn = 2x\nn = 3";
+        let error = finish(input, parse(input), ParseMode::Lenient).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected the end of the line\n  |\n2 | n = 2x\n  |      ^"
+        );
+
+        let input = "n = 1\nn = 256";
+        let error = finish(input, parse(input), ParseMode::Lenient).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: invalid value: number too large to fit in target type\n  \
+             |\n2 | n = 256\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn number_lists() {
+        assert_eq!(
+            unsigned_list::<u8>("  7  15   30\n"),
+            Ok(("\n", vec![7, 15, 30]))
+        );
+        assert_eq!(signed_list::<i8>("0 -3 6"), Ok(("", vec![0, -3, 6])));
+        assert_eq!(
+            labelled("Time:", unsigned_list::<u8>)("Time:      7  15"),
+            Ok(("", vec![7, 15]))
+        );
+
+        let input = "1 2 -3";
+        let error = finish(input, unsigned_list::<u8>(input), ParseMode::Strict).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 1, column 4: expected the end"));
+    }
+
+    #[test]
+    fn blocks_and_chars() {
+        let block = |input| {
+            separated_pair(terminated(alpha1, tag(":")), newline, lines(unsigned::<u8>))(input)
+        };
+
+        let input = "a:\n1\n2\n\nb:\n3\n";
+        assert_eq!(
+            blocks(block)(input),
+            Ok(("\n", vec![("a", vec![1, 2]), ("b", vec![3])]))
+        );
+
+        let input = "a:\n1\n\nb\n3";
+        let error = finish(input, blocks(block)(input), ParseMode::Lenient).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 4, column 2: expected \":\""));
+
+        let card = |c: char| match c {
+            'A' => Ok(14),
+            'K' => Ok(13),
+            _ => Err(format!("{c:?} is not a card")),
+        };
+        assert_eq!(
+            count(mapped_char(card), 3)("AKA 1"),
+            Ok((" 1", vec![14, 13, 14]))
+        );
+
+        let input = "AQK";
+        let error =
+            finish(input, count(mapped_char(card), 3)(input), ParseMode::Strict).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 1, column 2: invalid value: 'Q' is not a card"));
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_input("a\r\nb\r\n"), "a\nb");
+        assert_eq!(normalize_input("a\nb"), "a\nb");
+        assert_eq!(normalize_input("a \r\n\r\nb\t\n\n  \n"), "a\n\nb");
+        assert_eq!(normalize_input("  a\n  b"), "  a\n  b");
+
+        let input = normalize_input("n = 1\r\nn = x\r\n");
+        let error = finish(
+            &input,
+            lines(preceded(tag("n = "), unsigned::<u8>))(&input),
+            ParseMode::Strict,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 5: expected a digit"));
+    }
+}
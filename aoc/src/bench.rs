@@ -0,0 +1,238 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use aoc_core::{parsing::ParseMode, Day, Part};
+use serde::Serialize;
+
+use crate::run_all::panic_message;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve => f.pad("solve"),
+        }
+    }
+}
+
+/// How long one phase of a part of a day took over every iteration it was
+/// run for.
+#[derive(Debug, Clone, Serialize)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Outcome {
+    Timed(Stats),
+    Failed { error: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_ns", serialize_with = "nanoseconds")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanoseconds")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanoseconds")]
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples to summarize.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (*samples.get(middle.checked_sub(1)?)? + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Stats {
+            runs: samples.len(),
+            min: *samples.first()?,
+            median,
+            max: *samples.last()?,
+        })
+    }
+}
+
+fn nanoseconds<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Solves `parts` of a day `iterations` times, timing the parsing and the
+/// solving of each part separately. A part that fails or panics is not run
+/// again.
+pub fn bench(
+    solution: &Day,
+    parts: &[Part],
+    input: &[u8],
+    mode: ParseMode,
+    iterations: usize,
+) -> Vec<Benchmark> {
+    let mut benchmarks = Vec::new();
+
+    for &part in parts {
+        let mut parse_times = Vec::new();
+        let mut solve_times = Vec::new();
+        let mut error = None;
+
+        for _ in 0..iterations {
+            let run =
+                panic::catch_unwind(AssertUnwindSafe(|| solution.solve_bytes(part, input, mode)));
+            match run {
+                Ok(Ok(run)) => {
+                    parse_times.push(run.parse_time);
+                    solve_times.push(run.solve_time);
+                }
+                Ok(Err(report)) => {
+                    error = Some(format!("{report:#}"));
+                    break;
+                }
+                Err(payload) => {
+                    error = Some(format!("panicked: {}", panic_message(&*payload)));
+                    break;
+                }
+            }
+        }
+
+        // parsing may well have worked before the part failed, but the
+        // times are only known when both worked
+        let parse = match Stats::from_samples(parse_times) {
+            Some(stats) => Outcome::Timed(stats),
+            None => failed(error.clone()),
+        };
+        let solve = match (error, Stats::from_samples(solve_times)) {
+            (None, Some(stats)) => Outcome::Timed(stats),
+            (error, _) => failed(error),
+        };
+        for (phase, outcome) in [(Phase::Parse, parse), (Phase::Solve, solve)] {
+            benchmarks.push(Benchmark {
+                day: solution.day,
+                part: part.into(),
+                phase,
+                outcome,
+            });
+        }
+    }
+
+    benchmarks
+}
+
+fn failed(error: Option<String>) -> Outcome {
+    Outcome::Failed {
+        error: error.unwrap_or_else(|| "there were no iterations".to_string()),
+    }
+}
+
+pub fn print_table(benchmarks: &[Benchmark]) {
+    println!(
+        "{:>3}  {:>4}  {:<5}  {:>5}  {:>12}  {:>12}  {:>12}",
+        "day", "part", "phase", "runs", "min", "median", "max"
+    );
+    for benchmark in benchmarks {
+        match &benchmark.outcome {
+            Outcome::Timed(stats) => println!(
+                "{:>3}  {:>4}  {:<5}  {:>5}  {:>12}  {:>12}  {:>12}",
+                benchmark.day,
+                benchmark.part,
+                benchmark.phase,
+                stats.runs,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            ),
+            Outcome::Failed { error } => println!(
+                "{:>3}  {:>4}  {:<5}  failed: {error}",
+                benchmark.day, benchmark.part, benchmark.phase
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_core::{parsing::ParseMode, Day, Part};
+
+    use crate::{
+        bench::{bench, Outcome, Phase, Stats},
+        run_all::tests::{silence_broken_part, Sum},
+    };
+
+    #[test]
+    fn stats() {
+        let samples = [5, 1, 4, 2].map(Duration::from_millis).to_vec();
+        assert_eq!(
+            Stats::from_samples(samples),
+            Some(Stats {
+                runs: 4,
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            })
+        );
+
+        let samples = [3, 1, 2].map(Duration::from_millis).to_vec();
+        assert_eq!(
+            Stats::from_samples(samples).map(|stats| stats.median),
+            Some(Duration::from_millis(2))
+        );
+
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    #[test]
+    fn panicking_part() {
+        silence_broken_part();
+        let benchmarks = bench(
+            &Day::new::<Sum>(),
+            &Part::ALL,
+            b"1\n2\n",
+            ParseMode::Lenient,
+            3,
+        );
+        let outcomes = benchmarks
+            .iter()
+            .map(|benchmark| {
+                let outcome = match &benchmark.outcome {
+                    Outcome::Timed(stats) => format!("{} runs", stats.runs),
+                    Outcome::Failed { error } => error.clone(),
+                };
+                (benchmark.part, benchmark.phase, outcome)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            [
+                (1, Phase::Parse, "3 runs".to_string()),
+                (1, Phase::Solve, "3 runs".to_string()),
+                (2, Phase::Parse, "panicked: part 2 is broken".to_string()),
+                (2, Phase::Solve, "panicked: part 2 is broken".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::{fs, io, path::Path};
+
+use aoc_core::{
+    input::{self, InputSource},
+    parsing::ParseMode,
+    Solution,
+};
+use day_1::{Calibration, Day1, Document, Match, Report, Vocabulary};
+use eyre::{Result, WrapErr};
+use serde::Serialize;
+
+/// One line of a day 1 [`Report`] with every field flattened into a column,
+/// which is what the JSON and CSV output is made of.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub line: usize,
+    pub first: u32,
+    pub first_source: &'static str,
+    /// Where the first digit starts in the line, in bytes.
+    pub first_position: usize,
+    pub first_text: &'a str,
+    pub last: u32,
+    pub last_source: &'static str,
+    pub last_position: usize,
+    pub last_text: &'a str,
+    pub value: u32,
+}
+
+impl<'a> Record<'a> {
+    fn new(line: usize, calibration: &Calibration<'a>) -> Self {
+        let Calibration { first, last } = calibration;
+        Record {
+            line,
+            first: first.value,
+            first_source: source(first),
+            first_position: first.position,
+            first_text: first.text,
+            last: last.value,
+            last_source: source(last),
+            last_position: last.position,
+            last_text: last.text,
+            value: calibration.value(),
+        }
+    }
+}
+
+/// Reads day 1's calibration document from `source`, keeping the lines that
+/// are not valid UTF-8 so that the report can point them out.
+pub fn load(source: &InputSource) -> Result<Document> {
+    let input = input::load_bytes(Day1::DAY, source)?;
+    Day1::parse_bytes(&input, ParseMode::Lenient)
+}
+
+/// Reads the words to spell digits with from the file at `path`, with a word
+/// and its digit on each line.
+pub fn load_vocabulary(path: &Path) -> Result<Vocabulary> {
+    let words =
+        fs::read_to_string(path).wrap_err_with(|| format!("failed to read {}", path.display()))?;
+    Vocabulary::from_lines(&words)
+        .wrap_err_with(|| format!("{} is not a vocabulary", path.display()))
+}
+
+fn source(digit: &Match) -> &'static str {
+    if digit.is_spelled() {
+        "word"
+    } else {
+        "numeral"
+    }
+}
+
+fn records<'a>(report: &'a Report) -> impl Iterator<Item = Record<'a>> {
+    (1..)
+        .zip(&report.calibrations)
+        .map(|(line, calibration)| Record::new(line, calibration))
+}
+
+pub fn print_table(report: &Report) {
+    let describe = |digit: &Match| {
+        format!(
+            "{} {:?} {} @{}",
+            digit.value,
+            digit.text,
+            source(digit),
+            digit.position
+        )
+    };
+    println!("{:>5}  {:<24}  {:<24}  value", "line", "first", "last");
+    for (line, calibration) in (1..).zip(&report.calibrations) {
+        println!(
+            "{line:>5}  {:<24}  {:<24}  {:>5}",
+            describe(&calibration.first),
+            describe(&calibration.last),
+            calibration.value(),
+        );
+    }
+    println!("Total: {}", report.total);
+}
+
+pub fn print_json(report: &Report) -> Result<()> {
+    #[derive(Serialize)]
+    struct Json<'a> {
+        lines: Vec<Record<'a>>,
+        total: u32,
+    }
+
+    let json = Json {
+        lines: records(report).collect(),
+        total: report.total,
+    };
+    println!("{}", serde_json::to_string_pretty(&json)?);
+    Ok(())
+}
+
+/// Prints a row for every line, and the total to stderr so that only the rows
+/// go to a file.
+pub fn print_csv(report: &Report) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(io::stdout());
+    for record in records(report) {
+        writer.serialize(record)?;
+    }
+    writer.flush()?;
+    eprintln!("Total: {}", report.total);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_core::input::InputSource;
+    use day_1::{calibration_report, Vocabulary};
+
+    use crate::calibration::{load, load_vocabulary, records};
+
+    #[test]
+    fn flattened() {
+        let lines = ["two1nine", "x7"];
+        let report = calibration_report(&lines, &Vocabulary::english()).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(records(&report).next().unwrap()).unwrap(),
+            serde_json::json!({
+                "line": 1,
+                "first": 2,
+                "first_source": "word",
+                "first_position": 0,
+                "first_text": "two",
+                "last": 9,
+                "last_source": "word",
+                "last_position": 4,
+                "last_text": "nine",
+                "value": 29,
+            })
+        );
+
+        let mut csv = csv::Writer::from_writer(Vec::new());
+        for record in records(&report) {
+            csv.serialize(record).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(csv.into_inner().unwrap()).unwrap(),
+            "line,first,first_source,first_position,first_text,last,last_source,last_position,last_text,value\n\
+             1,2,word,0,two,9,word,4,nine,29\n\
+             2,7,numeral,1,7,7,numeral,1,7,77\n"
+        );
+    }
+
+    #[test]
+    fn not_utf8() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("input.txt");
+        fs::write(&path, b"1abc2\r\nx\xffseven\nnix\n").unwrap();
+
+        let document = load(&InputSource::Path(path)).unwrap();
+        assert_eq!(
+            document
+                .report(&Vocabulary::english())
+                .unwrap_err()
+                .to_string(),
+            "2 lines cannot be calibrated:\nline 2: invalid UTF-8 at byte 1\nline 3: no digits"
+        );
+    }
+
+    #[test]
+    fn vocabulary_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("german.txt");
+        fs::write(&path, "eins 1\nzwei 2\nzweiundzwanzig 9\n").unwrap();
+
+        let german = load_vocabulary(&path).unwrap().case_insensitive();
+        let report = calibration_report(&["xEINSzweiundzwanzig"], &german).unwrap();
+        assert_eq!(report.total, 19);
+
+        fs::write(&path, "eins\n").unwrap();
+        assert_eq!(
+            format!("{:#}", load_vocabulary(&path).unwrap_err()),
+            format!(
+                "{} is not a vocabulary: line 1: expected a word and a digit, found \"eins\"",
+                path.display()
+            )
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use eyre::{eyre, Result, WrapErr};
+
+use aoc_core::Part;
+
+use crate::{config::Config, submit::Verdict};
+
+/// Talks to the Advent of Code website as whoever the session token belongs
+/// to.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    year: u16,
+    session: String,
+}
+
+/// Where a day's input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// It had already been downloaded, so the website was not asked again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self> {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+            .build();
+
+        Ok(Client {
+            agent,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            year: config.year,
+            session: config.session()?.to_string(),
+        })
+    }
+
+    /// Downloads a day's puzzle input.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    /// Submits an answer to one part of a day's puzzle. Use
+    /// [`crate::submit::submit`] instead, which makes sure the same answer is
+    /// not submitted twice.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        Verdict::parse(&read_response(&url, response)?)
+    }
+
+    /// Downloads a day's puzzle input to `real-input.txt` in `directory`,
+    /// unless it is already there. Inputs never change, so one that has been
+    /// downloaded is never downloaded again. An empty file, like the one
+    /// `aoc new` creates, does not count.
+    pub fn fetch_input(&self, day: u8, directory: &Path) -> Result<Fetched> {
+        let path = directory.join("real-input.txt");
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+        if !directory.is_dir() {
+            return Err(eyre!(
+                "{} does not exist; create day {day} with `aoc new --day {day}` first",
+                directory.display()
+            ));
+        }
+
+        let input = self.input(day)?;
+        // write it somewhere else first, so that a download that fails half
+        // way does not look like a cached input
+        let partial = directory.join("real-input.txt.part");
+        fs::write(&partial, input)
+            .and_then(|()| fs::rename(&partial, &path))
+            .wrap_err_with(|| format!("failed to write {}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .wrap_err_with(|| format!("failed to read the response from {url}")),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let reason = body.lines().next().unwrap_or_default().trim();
+            Err(eyre!("{url} returned {status}: {reason}"))
+        }
+        Err(error) => Err(error).wrap_err_with(|| format!("failed to request {url}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{
+        client::{Client, Fetched},
+        config::Config,
+        test_server::TestServer,
+    };
+
+    fn client(server: &TestServer) -> Client {
+        Client::new(&Config {
+            session: Some("abc".to_string()),
+            base_url: server.base_url(),
+            year: 2023,
+            ..Config::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn fetch_once() {
+        let server = TestServer::start(|request| match request.url.as_str() {
+            "/2023/day/3/input" => (200, "467..114..\n...*......\n".to_string()),
+            _ => (404, "404 Not Found".to_string()),
+        });
+        let directory = tempfile::tempdir().unwrap();
+        let client = client(&server);
+
+        let path = directory.path().join("real-input.txt");
+        fs::write(&path, "").unwrap();
+        assert_eq!(
+            client.fetch_input(3, directory.path()).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "467..114..\n...*......\n"
+        );
+        assert_eq!(
+            client.fetch_input(3, directory.path()).unwrap(),
+            Fetched::Cached(path)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    }
+
+    #[test]
+    fn fetch_errors() {
+        let server = TestServer::start(|_| {
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            )
+        });
+        let directory = tempfile::tempdir().unwrap();
+        let client = client(&server);
+
+        let error = client.fetch_input(4, directory.path()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}/2023/day/4/input returned 400: Puzzle inputs differ by user.  Please log in \
+                 to get your puzzle input.",
+                server.base_url()
+            )
+        );
+        assert!(fs::read_dir(directory.path()).unwrap().next().is_none());
+
+        let missing = directory.path().join("day-4");
+        assert!(client.fetch_input(4, &missing).is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+}
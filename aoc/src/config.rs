@@ -0,0 +1,109 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::input::root_directory;
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+
+/// How to talk to the Advent of Code website, kept in `aoc.toml` at the root
+/// directory, which looks like this:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// year = 2023
+/// ```
+///
+/// The file holds a login token, so it is not committed.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie of a browser that is logged in to the website.
+    /// `AOC_SESSION` takes precedence over this.
+    pub session: Option<String>,
+    /// Where the website is, which is only worth changing to test against a
+    /// stand-in server.
+    pub base_url: String,
+    /// Which year's puzzles the days are.
+    pub year: u16,
+    /// How long to wait after a wrong answer before submitting another.
+    pub cooldown_seconds: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            year: 2023,
+            cooldown_seconds: 60,
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        root_directory().join("aoc.toml")
+    }
+
+    /// Reads the config from [`Config::path`], falling back to the defaults if
+    /// the file does not exist.
+    pub fn load() -> Result<Self> {
+        let mut config = Self::load_from(&Self::path())?;
+        if let Some(session) = env::var("AOC_SESSION")
+            .ok()
+            .filter(|session| !session.is_empty())
+        {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let config = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&config).wrap_err_with(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            eyre!(
+                "there is no session token; set `session` in {} or AOC_SESSION to the session \
+                 cookie of a browser that is logged in to Advent of Code",
+                Self::path().display()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::config::Config;
+
+    #[test]
+    fn load() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("aoc.toml");
+        assert_eq!(Config::load_from(&path).unwrap(), Config::default());
+
+        fs::write(
+            &path,
+            "session = \"abc\"\nbase_url = \"http://localhost:8080\"\n",
+        )
+        .unwrap();
+        let config = Config::load_from(&path).unwrap();
+        assert_eq!(config.session().unwrap(), "abc");
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.year, 2023);
+
+        fs::write(&path, "sesion = \"abc\"\n").unwrap();
+        assert!(Config::load_from(&path).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_core::Day;
+
+/// Every day that has a solution, in order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_1::Day1>(),
+        Day::new::<day_2::Day2>(),
+        Day::new::<day_3::Day3>(),
+        Day::new::<day_4::Day4>(),
+        Day::new::<day_5::Day5>(),
+        Day::new::<day_6::Day6>(),
+        Day::new::<day_7::Day7>(),
+        Day::new::<day_8::Day8>(),
+        Day::new::<day_9::Day9>(),
+        Day::new::<day_10::Day10>(),
+        Day::new::<day_11::Day11>(),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Day> {
+    all().into_iter().find(|solution| solution.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_core::{
+        input::{load, load_bytes, InputSource},
+        parsing::ParseMode,
+        Part, Unsolved,
+    };
+
+    use crate::days::{all, find};
+
+    /// Every example gives the same answers however its lines end, and with
+    /// trailing whitespace or blank lines after it.
+    #[test]
+    fn line_endings() {
+        for day in all() {
+            // the second example is usually the one for part 2
+            for (source, part) in [
+                (InputSource::Example1, Part::One),
+                (InputSource::Example2, Part::Two),
+            ] {
+                let input = load(day.day, &source).unwrap();
+                let input = input.trim_end();
+                let variants = [
+                    input.replace('\n', "\r\n"),
+                    format!("{input}\n"),
+                    format!("{}\r\n\r\n", input.replace('\n', "\r\n")),
+                    format!("{}  \n\n \n", input.replace('\n', " \n")),
+                ];
+
+                let expected = day.solve(part, input, ParseMode::Strict);
+                if part == Part::One {
+                    assert!(
+                        expected
+                            .as_ref()
+                            .map_or_else(|error| error.is::<Unsolved>(), |_| true),
+                        "day {} fails on its first example",
+                        day.day
+                    );
+                }
+                let expected = expected
+                    .map(|run| run.answer)
+                    .map_err(|error| format!("{error:#}"));
+
+                for variant in &variants {
+                    let actual = day
+                        .solve(part, variant, ParseMode::Strict)
+                        .map(|run| run.answer)
+                        .map_err(|error| format!("{error:#}"));
+                    assert_eq!(
+                        actual, expected,
+                        "day {} part {part} with {source} as {variant:?}",
+                        day.day
+                    );
+                }
+            }
+        }
+    }
+
+    /// Every day's generated inputs parse, and both parts of the puzzles they
+    /// describe are solved, unless they have not been solved yet.
+    #[test]
+    fn generated_inputs() {
+        for day in all() {
+            for size in 1..=3 {
+                for seed in 0..10 {
+                    let input = day.generate(seed, size);
+                    let context = format!("day {} with --size {size} --seed {seed}", day.day);
+                    for part in Part::ALL {
+                        match day.solve(part, &input, ParseMode::Strict) {
+                            Err(error) if !error.is::<Unsolved>() => {
+                                panic!("{context} fails part {part}: {error:#}\n{input}")
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Day 1 points out every line of an input that is not valid UTF-8, where
+    /// the other days give up on the whole input.
+    #[test]
+    fn not_utf8() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("input.txt");
+        fs::write(&path, b"1abc2\nx\xff7\n\xc3\n").unwrap();
+        let source = InputSource::Path(path);
+
+        assert!(load(1, &source).is_err());
+        let input = load_bytes(1, &source).unwrap();
+
+        let error = find(1)
+            .unwrap()
+            .solve_bytes(Part::One, &input, ParseMode::Strict)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2 lines cannot be calibrated:\nline 2: invalid UTF-8 at byte 1\n\
+             line 3: invalid UTF-8 at byte 0"
+        );
+
+        let error = find(2)
+            .unwrap()
+            .solve_bytes(Part::One, &input, ParseMode::Strict)
+            .unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "failed to parse input: the input is not valid UTF-8: \
+             invalid utf-8 sequence of 1 bytes from index 7"
+        );
+    }
+}
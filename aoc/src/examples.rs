@@ -0,0 +1,252 @@
+use std::{fs, path::Path};
+
+use eyre::{eyre, Result, WrapErr};
+
+use crate::html::{elements, text};
+
+/// The example of one part of a puzzle, as given in its description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// What the example's answer is, if the description says.
+    pub answer: Option<String>,
+}
+
+/// Finds the examples in a saved puzzle description, one for each part that
+/// the page describes. The description of a part is an `<article>`, its
+/// example is the first `<pre><code>` block in it, and its answer is the last
+/// `<code><em>` in it. Part 2 usually uses the example of part 1 again, so it
+/// does when it does not have one of its own.
+pub fn extract(page: &str) -> Result<Vec<Example>> {
+    let mut examples: Vec<Example> = Vec::new();
+    for article in elements(page, "<article", "</article>") {
+        let input = match elements(article, "<pre><code>", "</code></pre>").first() {
+            Some(block) => text(block),
+            None => match examples.first() {
+                Some(example) => example.input.clone(),
+                None => return Err(eyre!("the description of part 1 has no example")),
+            },
+        };
+        let answer = elements(article, "<code><em>", "</em></code>")
+            .last()
+            .map(|answer| text(answer));
+        examples.push(Example { input, answer });
+    }
+
+    if examples.is_empty() {
+        return Err(eyre!("the page does not describe a puzzle"));
+    }
+    Ok(examples)
+}
+
+/// Writes the examples into a day's directory as `test-input.txt` and
+/// `test-input-2.txt`, and their answers into the expected test outputs in
+/// its `src/lib.rs`. Examples that were already filled in are only replaced
+/// if `force` is set.
+pub fn write(directory: &Path, examples: &[Example], force: bool) -> Result<()> {
+    let [part1, rest @ ..] = examples else {
+        return Err(eyre!("there are no examples to write"));
+    };
+    let part2 = rest.first().unwrap_or(part1);
+
+    let files = [("test-input.txt", part1), ("test-input-2.txt", part2)];
+    for (file, _) in files {
+        let path = directory.join(file);
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if !force && !existing.trim().is_empty() {
+            return Err(eyre!(
+                "{} already has an example in it; pass --force to replace it",
+                path.display()
+            ));
+        }
+    }
+
+    let lib = directory.join("src/lib.rs");
+    let source =
+        fs::read_to_string(&lib).wrap_err_with(|| format!("failed to read {}", lib.display()))?;
+    let answers = [part1, part2].map(|example| example.answer.as_deref());
+    let source = expected_outputs(&source, answers)
+        .wrap_err_with(|| format!("failed to update {}", lib.display()))?;
+
+    for (file, example) in files {
+        let path = directory.join(file);
+        fs::write(&path, &example.input)
+            .wrap_err_with(|| format!("failed to write {}", path.display()))?;
+    }
+    fs::write(&lib, source).wrap_err_with(|| format!("failed to write {}", lib.display()))?;
+
+    Ok(())
+}
+
+/// Fills the answers into the `PART_N_EXPECTED_TEST_OUTPUT` constants of a
+/// day's source. A day that is still using the template's `u64` output is
+/// given another type if the answers do not fit it.
+fn expected_outputs(source: &str, answers: [Option<&str>; 2]) -> Result<String> {
+    let numeric = answers
+        .iter()
+        .flatten()
+        .all(|answer| answer.parse::<i64>().is_ok());
+    let literal = |answer: &str| {
+        if numeric {
+            answer.to_string()
+        } else {
+            format!("{answer:?}")
+        }
+    };
+
+    let mut source = source.to_string();
+    if source.contains("type Output = u64;") {
+        let output = if !numeric {
+            "&'static str"
+        } else if answers
+            .iter()
+            .flatten()
+            .any(|answer| answer.starts_with('-'))
+        {
+            "i64"
+        } else {
+            "u64"
+        };
+        source = source.replace("type Output = u64;", &format!("type Output = {output};"));
+    }
+
+    for (part, answer) in (1..).zip(answers) {
+        let Some(answer) = answer else {
+            continue;
+        };
+        let constant = format!("const PART_{part}_EXPECTED_TEST_OUTPUT: Output = ");
+        let start = source
+            .find(&constant)
+            .ok_or_else(|| eyre!("there is no PART_{part}_EXPECTED_TEST_OUTPUT"))?
+            + constant.len();
+        let end = start
+            + source[start..]
+                .find(';')
+                .ok_or_else(|| eyre!("PART_{part}_EXPECTED_TEST_OUTPUT does not end"))?;
+        source.replace_range(start..end, &literal(answer));
+    }
+
+    Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::examples::{expected_outputs, extract, write, Example};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 6: Wait For It ---</h2><p>For example:</p>
+<pre><code>Time:      7  15   30
+Distance:  9  40  200
+</code></pre>
+<p>This document describes three races:</p>
+<p>In this example, if you multiply these values together, you get <code><em>288</em></code> (<code>4</code> * <code>8</code> * <code>9</code>).</p>
+</article>
+<p>Your puzzle answer was <code>503424</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, the example from before now instead means this:</p>
+<pre><code>Time:      71530
+Distance:  940200
+</code></pre>
+<p>In this example, the race lasts for <em>71530 milliseconds</em>, so there are <code><em>71503</em></code> ways to win.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_examples() {
+        assert_eq!(
+            extract(PAGE).unwrap(),
+            [
+                Example {
+                    input: "Time:      7  15   30\nDistance:  9  40  200\n".to_string(),
+                    answer: Some("288".to_string()),
+                },
+                Example {
+                    input: "Time:      71530\nDistance:  940200\n".to_string(),
+                    answer: Some("71503".to_string()),
+                },
+            ]
+        );
+
+        let page = "<article><pre><code>a &lt;<em>b</em>&gt; &amp;c</code></pre>\
+                    <code><em>1</em></code> and <code><em>2</em></code></article>\
+                    <article><code><em>3</em></code></article>";
+        assert_eq!(
+            extract(page).unwrap(),
+            [
+                Example {
+                    input: "a <b> &c".to_string(),
+                    answer: Some("2".to_string()),
+                },
+                Example {
+                    input: "a <b> &c".to_string(),
+                    answer: Some("3".to_string()),
+                },
+            ]
+        );
+
+        assert!(extract("<html></html>").is_err());
+    }
+
+    #[test]
+    fn fill_in_outputs() {
+        let template = "type Output = u64;\n\
+                        const PART_1_EXPECTED_TEST_OUTPUT: Output = 0;\n\
+                        const PART_2_EXPECTED_TEST_OUTPUT: Output = 0;\n";
+        assert_eq!(
+            expected_outputs(template, [Some("288"), None]).unwrap(),
+            "type Output = u64;\n\
+             const PART_1_EXPECTED_TEST_OUTPUT: Output = 288;\n\
+             const PART_2_EXPECTED_TEST_OUTPUT: Output = 0;\n"
+        );
+        assert_eq!(
+            expected_outputs(template, [Some("-3"), Some("2")]).unwrap(),
+            "type Output = i64;\n\
+             const PART_1_EXPECTED_TEST_OUTPUT: Output = -3;\n\
+             const PART_2_EXPECTED_TEST_OUTPUT: Output = 2;\n"
+        );
+
+        let solved = "type Output = u32;\n\
+                      const PART_1_EXPECTED_TEST_OUTPUT: Output = 1;\n\
+                      const PART_2_EXPECTED_TEST_OUTPUT: Output = 2;\n";
+        assert_eq!(
+            expected_outputs(solved, [Some("4361"), Some("467835")]).unwrap(),
+            "type Output = u32;\n\
+             const PART_1_EXPECTED_TEST_OUTPUT: Output = 4361;\n\
+             const PART_2_EXPECTED_TEST_OUTPUT: Output = 467835;\n"
+        );
+    }
+
+    #[test]
+    fn write_examples() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+        fs::create_dir(directory.join("src")).unwrap();
+        fs::write(
+            directory.join("src/lib.rs"),
+            "type Output = u64;\n\
+             const PART_1_EXPECTED_TEST_OUTPUT: Output = 0;\n\
+             const PART_2_EXPECTED_TEST_OUTPUT: Output = 0;\n",
+        )
+        .unwrap();
+        fs::write(directory.join("test-input.txt"), "").unwrap();
+
+        let examples = extract(PAGE).unwrap();
+        write(directory, &examples, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(directory.join("test-input-2.txt")).unwrap(),
+            "Time:      71530\nDistance:  940200\n"
+        );
+        assert!(fs::read_to_string(directory.join("src/lib.rs"))
+            .unwrap()
+            .contains("PART_2_EXPECTED_TEST_OUTPUT: Output = 71503;"));
+
+        assert!(write(directory, &examples, false).is_err());
+        write(directory, &examples[..1], true).unwrap();
+        assert_eq!(
+            fs::read_to_string(directory.join("test-input-2.txt")).unwrap(),
+            "Time:      7  15   30\nDistance:  9  40  200\n"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+/// The contents of every element that starts with `open` and ends with
+/// `close`, without either of them.
+pub fn elements<'a>(page: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut elements = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find(open) {
+        let after_open = &rest[start + open.len()..];
+        // `<article` is followed by its attributes
+        let contents = if open.ends_with('>') {
+            after_open
+        } else {
+            match after_open.find('>') {
+                Some(end) => &after_open[end + 1..],
+                None => break,
+            }
+        };
+        let Some(end) = contents.find(close) else {
+            break;
+        };
+        elements.push(&contents[..end]);
+        rest = &contents[end + close.len()..];
+    }
+    elements
+}
+
+/// The text of some HTML, without its tags and with its entities decoded.
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
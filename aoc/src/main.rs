@@ -0,0 +1,563 @@
+mod bench;
+mod calibration;
+mod client;
+mod config;
+mod days;
+mod examples;
+mod html;
+mod run_all;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod test_server;
+mod watch;
+
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use aoc_core::{
+    answers::{Answers, Check},
+    input::{self, InputSource},
+    parsing::ParseMode,
+    Day, Part,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use client::{Client, Fetched};
+use config::Config;
+use day_1::Vocabulary;
+use eyre::{eyre, Result, WrapErr};
+use submit::{History, Verdict};
+use tracing_subscriber::{
+    filter::{LevelFilter, Targets},
+    fmt,
+    prelude::*,
+};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solutions are doing to stderr
+    #[arg(long, short, global = true)]
+    verbose: bool,
+    /// Log everything the solutions are doing to stderr, which can be a lot
+    #[arg(long, global = true)]
+    trace: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a day's puzzle with the given input
+    Run {
+        #[arg(
+            long,
+            required_unless_present = "all",
+            value_parser = clap::value_parser!(u8).range(1..=25)
+        )]
+        day: Option<u8>,
+        /// Solve every day and show a summary of the results
+        #[arg(long, conflicts_with_all = ["day", "record"])]
+        all: bool,
+        /// Solve the days in parallel
+        #[arg(long, conflicts_with = "day")]
+        parallel: bool,
+        /// Only solve this part, instead of both
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Show how long parsing and solving took
+        #[arg(long)]
+        time: bool,
+        /// Record the answers as the correct ones for this input, instead of
+        /// checking them against the recorded answers
+        #[arg(long, conflicts_with = "format")]
+        record: bool,
+        /// How to print the results. Anything but `text` also includes the
+        /// input, the time taken and whether each answer is correct
+        #[arg(long, value_enum, default_value_t = RunFormat::Text)]
+        format: RunFormat,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Creates a new day from the template and registers it with the runner
+    New {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Writes the examples and their answers from a saved puzzle description
+    /// into a day's example inputs and tests
+    Examples {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The puzzle description, saved from the browser as HTML
+        #[arg(long)]
+        page: PathBuf,
+        /// Replace examples that were already filled in
+        #[arg(long)]
+        force: bool,
+    },
+    /// Downloads puzzle inputs that have not been downloaded yet, using the
+    /// session token from aoc.toml or AOC_SESSION
+    Fetch {
+        /// Only download this day's input, instead of every solved day's
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Solves a part with the real input and submits the answer, unless it
+    /// is already known to be right or wrong
+    Submit {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this answer instead of solving the part
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Times parsing and solving over several runs
+    Bench {
+        /// Only benchmark this day, instead of every solved day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Only benchmark this part, instead of both
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// How many times to solve each part
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Makes up a random input for a day and prints it
+    Generate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How big to make it, in lines, hands, the side of a grid or
+        /// whatever else the puzzle has a list of
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Make the same input as another time with this seed, instead of a
+        /// new one
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Shows the digits that day 1 finds on each line of its calibration
+    /// document, and the value each line adds to the total
+    Calibration {
+        /// Which part's digits to look for: only numerals for part 1, or
+        /// spelled out digits too for part 2
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Spell out digits with the words in this file instead of the
+        /// part's, with a word and its digit on each line, like `eins 1`
+        #[arg(long, conflicts_with = "part")]
+        vocabulary: Option<PathBuf>,
+        /// Match spelled out digits whatever their case
+        #[arg(long)]
+        ignore_case: bool,
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+        /// Which input to use: `real`, `example1`, `example2`, a path to a
+        /// file, or `-` to read it from stdin
+        #[arg(long, default_value = "real", value_parser = parse_input_source)]
+        input: InputSource,
+    },
+    /// Reruns a day's examples and real input every time its source or
+    /// inputs change, showing how the answers and times changed
+    Watch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Which input to use: `real`, `example1`, `example2`, a path to a file,
+    /// or `-` to read it from stdin
+    #[arg(long, default_value = "real", value_parser = parse_input_source)]
+    input: InputSource,
+    /// Fail if the parser does not consume the whole input
+    #[arg(long)]
+    strict: bool,
+}
+
+impl InputArgs {
+    fn mode(&self) -> ParseMode {
+        if self.strict {
+            ParseMode::Strict
+        } else {
+            ParseMode::Lenient
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum RunFormat {
+    /// One line per part for a single day, or a table for `--all`
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    /// A table of the lines, followed by the total
+    Text,
+    /// The lines and the total
+    Json,
+    /// A row for each line, with the total on stderr
+    Csv,
+}
+
+fn parse_input_source(source: &str) -> Result<InputSource, String> {
+    source.parse().map_err(|error| format!("{error}"))
+}
+
+fn parts(part: Option<u8>) -> Result<Vec<Part>> {
+    Ok(match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec(),
+    })
+}
+
+fn run(day: u8, part: Option<u8>, time: bool, record: bool, args: &InputArgs) -> Result<()> {
+    let solution = days::find(day).ok_or_else(|| eyre!("day {day} has not been solved"))?;
+    let input = input::load_bytes(day, &args.input)?;
+    let mut answers = Answers::load()?;
+    let mut mismatches = 0;
+
+    for part in parts(part)? {
+        let run = solution
+            .solve_bytes(part, &input, args.mode())
+            .wrap_err_with(|| format!("failed to solve day {day} part {part}"))?;
+
+        let mut line = format!("Part {part}: {}", run.answer);
+        if time {
+            line += &format!(
+                " (parsed in {:.2?}, solved in {:.2?})",
+                run.parse_time, run.solve_time
+            );
+        }
+        if record {
+            answers.record(day, &args.input, part, &run.answer.to_string())?;
+            line += " [recorded]";
+        } else {
+            match answers.check(day, &args.input, part, &run.answer.to_string()) {
+                Check::Correct => line += " [correct]",
+                Check::Incorrect { expected } => {
+                    mismatches += 1;
+                    line += &format!(" [expected {expected}]");
+                }
+                Check::Unknown => {}
+            }
+        }
+        println!("{line}");
+    }
+
+    if record {
+        answers.save()?;
+    }
+    if mismatches > 0 {
+        return Err(eyre!(
+            "{mismatches} of the answers did not match the recorded answers"
+        ));
+    }
+
+    Ok(())
+}
+
+fn run_all(
+    solutions: &[Day],
+    part: Option<u8>,
+    parallel: bool,
+    format: RunFormat,
+    args: &InputArgs,
+) -> Result<()> {
+    let answers = Answers::load()?;
+    let results = run_all::run_all(
+        solutions,
+        &parts(part)?,
+        &args.input,
+        args.mode(),
+        &answers,
+        parallel,
+    );
+    match format {
+        RunFormat::Text => run_all::print_table(&results),
+        RunFormat::Json => run_all::print_json(&results)?,
+        RunFormat::Csv => run_all::print_csv(&results)?,
+        RunFormat::Markdown => run_all::print_markdown(&results),
+    }
+
+    let failures = results
+        .iter()
+        .filter(|result| result.status.is_failure())
+        .count();
+    if failures > 0 {
+        return Err(eyre!("{failures} of the {} parts failed", results.len()));
+    }
+
+    Ok(())
+}
+
+fn examples(day: u8, page: &Path, force: bool) -> Result<()> {
+    let directory = input::day_directory(day);
+    if !directory.is_dir() {
+        return Err(eyre!(
+            "{} does not exist; create day {day} with `aoc new --day {day}` first",
+            directory.display()
+        ));
+    }
+
+    let page =
+        fs::read_to_string(page).wrap_err_with(|| format!("failed to read {}", page.display()))?;
+    let examples = examples::extract(&page)?;
+    examples::write(&directory, &examples, force)?;
+
+    for (part, example) in (1..).zip(&examples) {
+        match &example.answer {
+            Some(answer) => println!("Part {part}: example with answer {answer}"),
+            None => println!("Part {part}: example without an answer"),
+        }
+    }
+    Ok(())
+}
+
+fn fetch(day: Option<u8>) -> Result<()> {
+    let client = Client::new(&Config::load()?)?;
+    let days = match day {
+        Some(day) => vec![day],
+        None => days::all().iter().map(|solution| solution.day).collect(),
+    };
+
+    for day in days {
+        match client.fetch_input(day, &input::day_directory(day))? {
+            Fetched::Cached(path) => println!("Day {day}: already in {}", path.display()),
+            Fetched::Downloaded(path) => println!("Day {day}: downloaded to {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(day: u8, part: u8, answer: Option<String>) -> Result<()> {
+    let part = Part::try_from(part)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = days::find(day).ok_or_else(|| eyre!("day {day} has not been solved"))?;
+            let input = input::load_bytes(day, &InputSource::Real)?;
+            let run = solution
+                .solve_bytes(part, &input, ParseMode::Lenient)
+                .wrap_err_with(|| format!("failed to solve day {day} part {part}"))?;
+            run.answer.to_string()
+        }
+    };
+
+    let config = Config::load()?;
+    let client = Client::new(&config)?;
+    let mut answers = Answers::load()?;
+    let mut history = History::load()?;
+    println!("Submitting {answer} for day {day} part {part}");
+    let verdict = submit::submit(
+        &client,
+        &mut answers,
+        &mut history,
+        (day, part),
+        &answer,
+        SystemTime::now(),
+        Duration::from_secs(config.cooldown_seconds),
+    )?;
+    answers.save()?;
+    history.save()?;
+
+    println!("{}", capitalize(&verdict.to_string()));
+    if verdict != Verdict::Correct {
+        return Err(eyre!("the answer was not accepted"));
+    }
+    Ok(())
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn generate(day: u8, size: usize, seed: Option<u64>) -> Result<()> {
+    let solution = days::find(day).ok_or_else(|| eyre!("day {day} has not been solved"))?;
+    let seed = seed.unwrap_or_else(rand::random);
+    // on stderr, so that only the input goes to a file
+    eprintln!("Generating day {day} with --seed {seed}");
+    println!("{}", solution.generate(seed, size));
+    Ok(())
+}
+
+fn calibration(
+    part: u8,
+    vocabulary: Option<&Path>,
+    ignore_case: bool,
+    format: ReportFormat,
+    source: &InputSource,
+) -> Result<()> {
+    let mut vocabulary = match vocabulary {
+        Some(path) => calibration::load_vocabulary(path)?,
+        None => match Part::try_from(part)? {
+            Part::One => Vocabulary::numerals(),
+            Part::Two => Vocabulary::english(),
+        },
+    };
+    if ignore_case {
+        vocabulary = vocabulary.case_insensitive();
+    }
+    let document = calibration::load(source)?;
+    let report = document.report(&vocabulary)?;
+
+    match format {
+        ReportFormat::Text => calibration::print_table(&report),
+        ReportFormat::Json => calibration::print_json(&report)?,
+        ReportFormat::Csv => calibration::print_csv(&report)?,
+    }
+    Ok(())
+}
+
+fn bench(
+    day: Option<u8>,
+    part: Option<u8>,
+    iterations: u32,
+    format: Format,
+    args: &InputArgs,
+) -> Result<()> {
+    let solutions = match day {
+        Some(day) => vec![days::find(day).ok_or_else(|| eyre!("day {day} has not been solved"))?],
+        None => days::all(),
+    };
+    let parts = parts(part)?;
+
+    let mut benchmarks = Vec::new();
+    for solution in solutions {
+        let input = input::load_bytes(solution.day, &args.input)?;
+        benchmarks.extend(bench::bench(
+            &solution,
+            &parts,
+            &input,
+            args.mode(),
+            iterations as usize,
+        ));
+    }
+
+    match format {
+        Format::Table => bench::print_table(&benchmarks),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&benchmarks)?),
+    }
+
+    let failures = benchmarks
+        .iter()
+        .filter(|benchmark| matches!(benchmark.outcome, bench::Outcome::Failed { .. }))
+        .count();
+    if failures > 0 {
+        return Err(eyre!("{failures} of the benchmarks failed"));
+    }
+
+    Ok(())
+}
+
+/// Sends the solutions' logs to stderr, so that the answers on stdout stay
+/// clean. `AOC_LOG` can set the level per day, e.g. `day_10=trace,day_7=debug`.
+fn init_logging(verbose: bool, trace: bool) -> Result<()> {
+    let level = if trace {
+        LevelFilter::TRACE
+    } else if verbose {
+        LevelFilter::DEBUG
+    } else {
+        LevelFilter::OFF
+    };
+    let mut filter = match std::env::var("AOC_LOG") {
+        Ok(targets) => targets
+            .parse::<Targets>()
+            .wrap_err_with(|| format!("invalid AOC_LOG {targets:?}"))?,
+        Err(_) => Targets::new(),
+    };
+    if verbose || trace || filter.default_level().is_none() {
+        filter = filter.with_default(level);
+    }
+
+    tracing_subscriber::registry()
+        .with(
+            fmt::layer()
+                .with_writer(io::stderr)
+                .with_ansi(io::stderr().is_terminal()),
+        )
+        .with(filter)
+        .try_init()?;
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    init_logging(cli.verbose, cli.trace)?;
+
+    match cli.command {
+        Command::Run {
+            day,
+            all: _,
+            parallel,
+            part,
+            time,
+            record,
+            format,
+            input,
+        } => match day {
+            Some(day) if format == RunFormat::Text => run(day, part, time, record, &input),
+            Some(day) => {
+                let solution =
+                    days::find(day).ok_or_else(|| eyre!("day {day} has not been solved"))?;
+                run_all(&[solution], part, false, format, &input)
+            }
+            None => run_all(&days::all(), part, parallel, format, &input),
+        },
+        Command::New { day } => {
+            scaffold::new_day(&input::root_directory(), day)?;
+            println!(
+                "Created day-{day}. Download the puzzle input with `aoc fetch --day {day}`, and \
+                 fill in the examples from the saved puzzle page with \
+                 `aoc examples --day {day} --page <file>`."
+            );
+            Ok(())
+        }
+        Command::Examples { day, page, force } => examples(day, &page, force),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part, answer } => submit(day, part, answer),
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            format,
+            input,
+        } => bench(day, part, iterations, format, &input),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Calibration {
+            part,
+            vocabulary,
+            ignore_case,
+            format,
+            input,
+        } => calibration(part, vocabulary.as_deref(), ignore_case, format, &input),
+        Command::Watch { day } => watch::watch(&input::root_directory(), day),
+    }
+}
@@ -0,0 +1,492 @@
+use std::{
+    any::Any,
+    io,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use aoc_core::{
+    answers::{Answers, Check},
+    input::{self, InputSource},
+    parsing::ParseMode,
+    Day, Part,
+};
+use eyre::Result;
+use rayon::prelude::*;
+use serde::Serialize;
+
+/// What happened when one part of a day was solved.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    /// Which input was used, as it is given on the command line.
+    pub input: String,
+    pub answer: Option<String>,
+    /// How long parsing and solving took together, if they finished.
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// There is no recorded answer to check against.
+    Unknown,
+    /// The solution returned an error or panicked.
+    Failed {
+        error: String,
+    },
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Incorrect { .. } | Status::Failed { .. })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Incorrect { .. } => "incorrect",
+            Status::Unknown => "unknown",
+            Status::Failed { .. } => "failed",
+        }
+    }
+
+    /// The status on one line, for the tables.
+    fn summary(&self) -> String {
+        match self {
+            Status::Incorrect { expected } => format!("incorrect, expected {expected}"),
+            // parse errors point at the input over several lines, which
+            // would break up the tables
+            Status::Failed { error } => {
+                format!("failed: {}", error.lines().next().unwrap_or_default())
+            }
+            status => status.name().to_string(),
+        }
+    }
+}
+
+/// A [`PartResult`] with every field flattened into a column, which is what
+/// the JSON, CSV and Markdown output is made of.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub input: &'a str,
+    pub answer: Option<&'a str>,
+    pub duration_ns: Option<u128>,
+    pub status: &'static str,
+    pub expected: Option<&'a str>,
+    pub error: Option<&'a str>,
+}
+
+impl PartResult {
+    pub fn record(&self) -> Record<'_> {
+        Record {
+            day: self.day,
+            part: self.part.into(),
+            input: &self.input,
+            answer: self.answer.as_deref(),
+            duration_ns: self.time.map(|time| time.as_nanos()),
+            status: self.status.name(),
+            expected: match &self.status {
+                Status::Incorrect { expected } => Some(expected),
+                _ => None,
+            },
+            error: match &self.status {
+                Status::Failed { error } => Some(error),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Solves `parts` of every one of `solutions` and checks the answers against
+/// `answers`. A part that panics is reported as failed instead of taking the
+/// other days down with it. The results are in the order of `solutions`,
+/// whether or not they were solved in parallel.
+pub fn run_all(
+    solutions: &[Day],
+    parts: &[Part],
+    source: &InputSource,
+    mode: ParseMode,
+    answers: &Answers,
+    parallel: bool,
+) -> Vec<PartResult> {
+    let run_day = |solution: &Day| run_day(solution, parts, source, mode, answers);
+    if parallel {
+        solutions.par_iter().flat_map_iter(run_day).collect()
+    } else {
+        solutions.iter().flat_map(run_day).collect()
+    }
+}
+
+fn run_day(
+    solution: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    mode: ParseMode,
+    answers: &Answers,
+) -> Vec<PartResult> {
+    let day = solution.day;
+    let input = input::load_bytes(day, source);
+
+    parts
+        .iter()
+        .map(|&part| {
+            let (answer, time, status) = match &input {
+                Ok(input) => solve(solution, part, input, source, mode, answers),
+                Err(error) => (
+                    None,
+                    None,
+                    Status::Failed {
+                        error: format!("{error:#}"),
+                    },
+                ),
+            };
+            PartResult {
+                day,
+                part,
+                input: source.to_string(),
+                answer,
+                time,
+                status,
+            }
+        })
+        .collect()
+}
+
+fn solve(
+    solution: &Day,
+    part: Part,
+    input: &[u8],
+    source: &InputSource,
+    mode: ParseMode,
+    answers: &Answers,
+) -> (Option<String>, Option<Duration>, Status) {
+    let run = panic::catch_unwind(AssertUnwindSafe(|| solution.solve_bytes(part, input, mode)));
+    match run {
+        Ok(Ok(run)) => {
+            let answer = run.answer.to_string();
+            let status = match answers.check(solution.day, source, part, &answer) {
+                Check::Correct => Status::Correct,
+                Check::Incorrect { expected } => Status::Incorrect { expected },
+                Check::Unknown => Status::Unknown,
+            };
+            (Some(answer), Some(run.parse_time + run.solve_time), status)
+        }
+        Ok(Err(error)) => (
+            None,
+            None,
+            Status::Failed {
+                error: format!("{error:#}"),
+            },
+        ),
+        Err(payload) => (
+            None,
+            None,
+            Status::Failed {
+                error: format!("panicked: {}", panic_message(&*payload)),
+            },
+        ),
+    }
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+pub fn print_table(results: &[PartResult]) {
+    let answer_width = results
+        .iter()
+        .filter_map(|result| result.answer.as_ref().map(String::len))
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>12}  status",
+        "day", "part", "answer", "time"
+    );
+    for result in results {
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>12}  {}",
+            result.day,
+            result.part,
+            result.answer.as_deref().unwrap_or("-"),
+            result
+                .time
+                .map_or("-".to_string(), |time| format!("{time:.2?}")),
+            result.status.summary(),
+        );
+    }
+}
+
+pub fn print_json(results: &[PartResult]) -> Result<()> {
+    let records = results.iter().map(PartResult::record).collect::<Vec<_>>();
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
+
+pub fn print_csv(results: &[PartResult]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(io::stdout());
+    for result in results {
+        writer.serialize(result.record())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn print_markdown(results: &[PartResult]) {
+    print!("{}", markdown(results));
+}
+
+fn markdown(results: &[PartResult]) -> String {
+    let mut table = "| day | part | input | answer | duration | status |\n".to_string();
+    table += "| --: | --: | --- | --- | --: | --- |\n";
+    for result in results {
+        let cells = [
+            result.day.to_string(),
+            result.part.to_string(),
+            result.input.clone(),
+            result.answer.clone().unwrap_or_default(),
+            result
+                .time
+                .map(|time| format!("{time:.2?}"))
+                .unwrap_or_default(),
+            result.status.summary(),
+        ];
+        let cells = cells.map(|cell| cell.replace('|', "\\|"));
+        table += &format!("| {} |\n", cells.join(" | "));
+    }
+    table
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{fs, panic, sync::Once, time::Duration};
+
+    use aoc_core::{
+        answers::Answers,
+        input::InputSource,
+        parsing::{ParseMode, ParseResult},
+        Day, Generate, Part, Solution,
+    };
+    use eyre::{eyre, Result};
+    use rand::Rng;
+
+    use crate::run_all::{markdown, run_all, PartResult, Status};
+
+    pub(crate) struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        const PART_1_EXPECTED_TEST_OUTPUT: u32 = 0;
+        const PART_2_EXPECTED_TEST_OUTPUT: u32 = 0;
+
+        fn parse_input(input: &str) -> ParseResult<'_, Vec<u32>> {
+            Ok((
+                "",
+                input.lines().map(|line| line.parse().unwrap()).collect(),
+            ))
+        }
+
+        fn solve_part1(input: Vec<u32>) -> Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn solve_part2(_: Vec<u32>) -> Result<u32> {
+            panic!("part 2 is broken")
+        }
+    }
+
+    /// Keeps the panic of [`Sum`]'s part 2 out of the test output. Every
+    /// other panic still goes to the hook that was there before, so the
+    /// other tests that run at the same time are not affected.
+    pub(crate) fn silence_broken_part() {
+        static SILENCE: Once = Once::new();
+        SILENCE.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if info.payload().downcast_ref::<&str>() != Some(&"part 2 is broken") {
+                    hook(info);
+                }
+            }));
+        });
+    }
+
+    impl Generate for Sum {
+        fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+            let numbers = (0..size).map(|_| rng.gen_range(0..100).to_string());
+            numbers.collect::<Vec<_>>().join("\n")
+        }
+    }
+
+    struct Unsolved;
+
+    impl Solution for Unsolved {
+        const DAY: u8 = 2;
+
+        type Input = ();
+        type Output = u32;
+
+        const PART_1_EXPECTED_TEST_OUTPUT: u32 = 0;
+        const PART_2_EXPECTED_TEST_OUTPUT: u32 = 0;
+
+        fn parse_input(input: &str) -> ParseResult<'_, ()> {
+            Ok((input, ()))
+        }
+
+        fn solve_part1(_: ()) -> Result<u32> {
+            Ok(7)
+        }
+
+        fn solve_part2(_: ()) -> Result<u32> {
+            Err(eyre!("part 2 has not been solved yet"))
+        }
+    }
+
+    impl Generate for Unsolved {
+        fn generate<R: Rng + ?Sized>(_: &mut R, _: usize) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn statuses() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("input.txt");
+        fs::write(&path, "1\n2\n3\n").unwrap();
+        let source = InputSource::Path(path);
+
+        let mut answers = Answers::default();
+        answers.record(1, &source, Part::One, "6").unwrap();
+        answers.record(2, &source, Part::One, "8").unwrap();
+
+        silence_broken_part();
+        let days = [Day::new::<Sum>(), Day::new::<Unsolved>()];
+        let sequential = run_all(
+            &days,
+            &Part::ALL,
+            &source,
+            ParseMode::Lenient,
+            &answers,
+            false,
+        );
+        let parallel = run_all(
+            &days,
+            &Part::ALL,
+            &source,
+            ParseMode::Lenient,
+            &answers,
+            true,
+        );
+
+        for results in [sequential, parallel] {
+            let statuses = results
+                .iter()
+                .map(|result| (result.day, result.part, result.status.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                statuses,
+                [
+                    (1, Part::One, Status::Correct),
+                    (
+                        1,
+                        Part::Two,
+                        Status::Failed {
+                            error: "panicked: part 2 is broken".to_string()
+                        }
+                    ),
+                    (
+                        2,
+                        Part::One,
+                        Status::Incorrect {
+                            expected: "8".to_string()
+                        }
+                    ),
+                    (
+                        2,
+                        Part::Two,
+                        Status::Failed {
+                            error: "part 2 has not been solved yet".to_string()
+                        }
+                    ),
+                ]
+            );
+            assert_eq!(results[0].answer.as_deref(), Some("6"));
+        }
+    }
+
+    #[test]
+    fn formats() {
+        let results = [
+            PartResult {
+                day: 1,
+                part: Part::One,
+                input: "real".to_string(),
+                answer: Some("54601".to_string()),
+                time: Some(Duration::from_micros(1500)),
+                status: Status::Correct,
+            },
+            PartResult {
+                day: 1,
+                part: Part::Two,
+                input: "real".to_string(),
+                answer: None,
+                time: None,
+                status: Status::Failed {
+                    error: "failed to parse input: line 1, column 1: expected \"a|b\"\n  |"
+                        .to_string(),
+                },
+            },
+        ];
+
+        assert_eq!(
+            serde_json::to_value(results[0].record()).unwrap(),
+            serde_json::json!({
+                "day": 1,
+                "part": 1,
+                "input": "real",
+                "answer": "54601",
+                "duration_ns": 1_500_000,
+                "status": "correct",
+                "expected": null,
+                "error": null,
+            })
+        );
+
+        let mut csv = csv::Writer::from_writer(Vec::new());
+        for result in &results {
+            csv.serialize(result.record()).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(csv.into_inner().unwrap()).unwrap(),
+            "day,part,input,answer,duration_ns,status,expected,error\n\
+             1,1,real,54601,1500000,correct,,\n\
+             1,2,real,,,failed,,\"failed to parse input: line 1, column 1: expected \"\"a|b\"\"\n  |\"\n"
+        );
+
+        assert_eq!(
+            markdown(&results),
+            "| day | part | input | answer | duration | status |\n\
+             | --: | --: | --- | --- | --: | --- |\n\
+             | 1 | 1 | real | 54601 | 1.50ms | correct |\n\
+             | 1 | 2 | real |  |  | failed: failed to parse input: line 1, column 1: expected \"a\\|b\" |\n"
+        );
+    }
+}
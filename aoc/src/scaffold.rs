@@ -0,0 +1,233 @@
+use std::{fs, path::Path};
+
+use eyre::{eyre, Result, WrapErr};
+
+const TEMPLATE_MANIFEST: &str = include_str!("../../template/Cargo.toml");
+const TEMPLATE_LIB: &str = include_str!("../../template/src/lib.rs");
+
+/// The input files every day starts out with, empty until the puzzle is
+/// opened.
+const INPUT_FILES: [&str; 3] = ["real-input.txt", "test-input.txt", "test-input-2.txt"];
+
+/// Creates the `day-N` crate under `root` from the template and registers it
+/// with the workspace and the runner.
+pub fn new_day(root: &Path, day: u8) -> Result<()> {
+    let directory = root.join(format!("day-{day}"));
+    if directory.exists() {
+        return Err(eyre!("{} already exists", directory.display()));
+    }
+
+    // Register the day first, so that nothing is left behind if one of the
+    // files it is registered in does not look as expected.
+    let registrations = [
+        (
+            root.join("Cargo.toml"),
+            format!("day-{day} = {{ path = \"day-{day}\" }}"),
+            "day-",
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            format!("day-{day}.workspace = true"),
+            "day-",
+        ),
+        (
+            root.join("aoc/src/days.rs"),
+            format!("        Day::new::<day_{day}::Day{day}>(),"),
+            "Day::new::<day_",
+        ),
+    ];
+    let registrations = registrations
+        .into_iter()
+        .map(|(path, line, prefix)| {
+            let contents = fs::read_to_string(&path)
+                .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+            let contents = insert_line(&contents, &line, day, prefix)
+                .wrap_err_with(|| format!("failed to register day {day} in {}", path.display()))?;
+            Ok((path, contents))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    fs::create_dir_all(directory.join("src"))?;
+    fs::write(directory.join("Cargo.toml"), manifest(day))?;
+    fs::write(directory.join("src/lib.rs"), lib(day))?;
+    for file in INPUT_FILES {
+        fs::write(directory.join(file), "")?;
+    }
+
+    for (path, contents) in registrations {
+        fs::write(&path, contents)
+            .wrap_err_with(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+fn manifest(day: u8) -> String {
+    TEMPLATE_MANIFEST.replace("name = \"template\"", &format!("name = \"day-{day}\""))
+}
+
+fn lib(day: u8) -> String {
+    TEMPLATE_LIB
+        .replace("DayN", &format!("Day{day}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+/// Inserts `line` among the lines that register a day, which are the ones
+/// whose trimmed text starts with `prefix` followed by the day number, so
+/// that the days stay in order.
+fn insert_line(contents: &str, line: &str, day: u8, prefix: &str) -> Result<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let registered = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, registered_day(line, prefix)?)))
+        .collect::<Vec<_>>();
+
+    if registered.iter().any(|&(_, registered)| registered == day) {
+        return Err(eyre!("day {day} is already registered"));
+    }
+    let index = match registered.iter().find(|&&(_, registered)| registered > day) {
+        Some(&(index, _)) => index,
+        None => match registered.last() {
+            Some(&(index, _)) => index + 1,
+            None => return Err(eyre!("no other days are registered")),
+        },
+    };
+
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn registered_day(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    rest[..digits].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, process::Command};
+
+    use crate::scaffold::{insert_line, lib, manifest, new_day};
+
+    #[test]
+    fn insert_in_order() {
+        let contents = "[dependencies]\nday-1 = 1\nday-3 = 3\nday-10 = 10\neyre = 1\n";
+        assert_eq!(
+            insert_line(contents, "day-2 = 2", 2, "day-").unwrap(),
+            "[dependencies]\nday-1 = 1\nday-2 = 2\nday-3 = 3\nday-10 = 10\neyre = 1\n"
+        );
+        assert_eq!(
+            insert_line(contents, "day-12 = 12", 12, "day-").unwrap(),
+            "[dependencies]\nday-1 = 1\nday-3 = 3\nday-10 = 10\nday-12 = 12\neyre = 1\n"
+        );
+        assert!(insert_line(contents, "day-3 = 3", 3, "day-").is_err());
+        assert!(insert_line("eyre = 1\n", "day-3 = 3", 3, "day-").is_err());
+    }
+
+    #[test]
+    fn render_template() {
+        assert!(manifest(12).contains("name = \"day-12\""));
+
+        let lib = lib(12);
+        assert!(lib.contains("pub struct Day12;"));
+        assert!(lib.contains("const DAY: u8 = 12;"));
+        assert!(lib.contains("check_examples::<Day12>()"));
+        assert!(!lib.contains("DayN"));
+    }
+
+    #[test]
+    fn scaffold() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace.dependencies]\nday-1 = { path = \"day-1\" }\neyre = \"0.6.9\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\neyre.workspace = true\nday-1.workspace = true\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub fn all() -> Vec<Day> {\n    vec![\n        Day::new::<day_1::Day1>(),\n    ]\n}\n",
+        )
+        .unwrap();
+
+        new_day(root, 2).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace.dependencies]\nday-1 = { path = \"day-1\" }\n\
+             day-2 = { path = \"day-2\" }\neyre = \"0.6.9\"\n"
+        );
+        assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .ends_with("day-1.workspace = true\nday-2.workspace = true\n"));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("Day::new::<day_1::Day1>(),\n        Day::new::<day_2::Day2>(),\n    ]"));
+        for file in [
+            "Cargo.toml",
+            "src/lib.rs",
+            "real-input.txt",
+            "test-input.txt",
+        ] {
+            assert!(root.join("day-2").join(file).exists(), "{file} is missing");
+        }
+
+        assert!(new_day(root, 2).is_err());
+    }
+
+    /// A new day builds without warnings as it is, before anything has been
+    /// filled in. This runs cargo, so it is slow and only run with
+    /// `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    fn scaffold_builds() {
+        let repository = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            format!(
+                "[workspace]\nresolver = \"2\"\nmembers = [\"day-*\"]\n\n\
+                 [workspace.package]\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+                 [workspace.dependencies]\n\
+                 aoc-core = {{ path = {:?} }}\n\
+                 day-1 = {{ path = \"day-1\" }}\n\
+                 eyre = \"0.6.9\"\nnom = \"7.1.3\"\nrand = \"0.8.5\"\n",
+                repository.join("aoc-core")
+            ),
+        )
+        .unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "day-1.workspace = true\n").unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "        Day::new::<day_1::Day1>(),\n",
+        )
+        .unwrap();
+        // the same versions of the dependencies as the repository, which are
+        // already downloaded
+        fs::copy(repository.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+
+        new_day(root, 12).unwrap();
+
+        let output = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+            .args(["clippy", "--all-targets", "--package", "day-12"])
+            .args(["--", "--deny", "warnings"])
+            .env("CARGO_TARGET_DIR", repository.join("target/scaffold"))
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{
+    answers::Answers,
+    input::{root_directory, InputSource},
+    Part,
+};
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Client,
+    html::{elements, text},
+};
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    /// The hint is whether the answer is too high or too low, when the
+    /// website says.
+    Wrong {
+        hint: Option<String>,
+    },
+    /// An answer was submitted too recently to try another one yet.
+    TooSoon {
+        wait_seconds: Option<u64>,
+    },
+    /// The part has already been solved, or part 1 has not been yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the website responds with.
+    pub fn parse(page: &str) -> Result<Self> {
+        let message = article(page);
+        if message.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if message.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| message.contains(hint))
+                .map(str::to_string);
+            Ok(Verdict::Wrong { hint })
+        } else if message.contains("You gave an answer too recently") {
+            Ok(Verdict::TooSoon {
+                wait_seconds: wait_seconds(&message),
+            })
+        } else if message.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err(eyre!("unexpected response to the answer: {message:?}"))
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::Wrong { hint: Some(hint) } => {
+                write!(f, "that's not the right answer, it is {hint}")
+            }
+            Verdict::Wrong { hint: None } => write!(f, "that's not the right answer"),
+            Verdict::TooSoon {
+                wait_seconds: Some(wait),
+            } => write!(f, "an answer was given too recently, wait {wait}s"),
+            Verdict::TooSoon { wait_seconds: None } => {
+                write!(f, "an answer was given too recently")
+            }
+            Verdict::WrongLevel => write!(
+                f,
+                "this part cannot be answered, either it already was or part 1 has not been"
+            ),
+        }
+    }
+}
+
+/// The text of the `<article>` the verdict is in, on one line.
+fn article(page: &str) -> String {
+    let article = elements(page, "<article", "</article>")
+        .first()
+        .map_or_else(|| text(page), |article| text(article));
+    article.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the wait out of "You have 1m 5s left to wait."
+fn wait_seconds(message: &str) -> Option<u64> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+    message[start..end]
+        .split_whitespace()
+        .map(|amount| match amount.strip_suffix('m') {
+            Some(minutes) => minutes.parse::<u64>().ok().map(|minutes| minutes * 60),
+            None => amount.strip_suffix('s')?.parse().ok(),
+        })
+        .sum()
+}
+
+/// Every answer that has been submitted, kept in `submissions.toml` at the
+/// root directory so that the cooldown between answers is kept across runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub submitted_at: u64,
+    pub verdict: Verdict,
+}
+
+impl History {
+    pub fn path() -> PathBuf {
+        root_directory().join("submissions.toml")
+    }
+
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::path())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let history = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&history).wrap_err_with(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let history = toml::to_string(self)?;
+        fs::write(path, history).wrap_err_with(|| format!("failed to write {}", path.display()))
+    }
+
+    /// When the website will take another answer, if it will not right
+    /// away. The website asks for a `cooldown` after every wrong answer, for
+    /// any puzzle, unless it said how long to wait.
+    pub fn next_allowed(&self, cooldown: Duration) -> Option<SystemTime> {
+        let last = self.submissions.last()?;
+        let wait = match last.verdict {
+            Verdict::Wrong { .. } | Verdict::TooSoon { wait_seconds: None } => cooldown,
+            Verdict::TooSoon {
+                wait_seconds: Some(wait),
+            } => Duration::from_secs(wait),
+            Verdict::Correct | Verdict::WrongLevel => return None,
+        };
+        Some(UNIX_EPOCH + Duration::from_secs(last.submitted_at) + wait)
+    }
+}
+
+/// Submits `answer` for the real input of a day, unless it is already known
+/// to be right or wrong or the cooldown has not passed yet, and records what
+/// the website says about it in `answers` and `history`.
+pub fn submit(
+    client: &Client,
+    answers: &mut Answers,
+    history: &mut History,
+    (day, part): (u8, Part),
+    answer: &str,
+    now: SystemTime,
+    cooldown: Duration,
+) -> Result<Verdict> {
+    let source = InputSource::Real;
+    if let Some(known) = answers.get(day, &source, part) {
+        return Err(eyre!(
+            "day {day} part {part} has already been answered with {known}"
+        ));
+    }
+    if answers.is_wrong(day, &source, part, answer) {
+        return Err(eyre!(
+            "{answer} was already submitted for day {day} part {part}, and it was wrong"
+        ));
+    }
+    if let Some(wait) = history
+        .next_allowed(cooldown)
+        .and_then(|next| next.duration_since(now).ok())
+        .filter(|wait| !wait.is_zero())
+    {
+        return Err(eyre!(
+            "the last answer was submitted too recently, wait {}s before submitting another",
+            wait.as_secs_f64().ceil()
+        ));
+    }
+
+    let verdict = client.submit(day, part, answer)?;
+    history.submissions.push(Submission {
+        day,
+        part: part.into(),
+        answer: answer.to_string(),
+        submitted_at: now.duration_since(UNIX_EPOCH)?.as_secs(),
+        verdict: verdict.clone(),
+    });
+    match verdict {
+        Verdict::Correct => answers.record(day, &source, part, answer)?,
+        Verdict::Wrong { .. } => answers.record_wrong(day, &source, part, answer)?,
+        Verdict::TooSoon { .. } | Verdict::WrongLevel => {}
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use aoc_core::{answers::Answers, input::InputSource, Part};
+
+    use crate::{
+        client::Client,
+        config::Config,
+        submit::{submit, History, Verdict},
+        test_server::TestServer,
+    };
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn verdicts() {
+        let verdict = |message| Verdict::parse(&page(message)).unwrap();
+
+        assert_eq!(
+            verdict(
+                "That's the right answer!  You are one gold star closer to restoring snow \
+                 operations. <a href=\"/2023/day/1#part2\">[Continue to Part Two]</a>"
+            ),
+            Verdict::Correct
+        );
+        assert_eq!(
+            verdict(
+                "That's not the right answer; your answer is too high.  If you're stuck, make \
+                 sure you're using the full input data. Please wait one minute before trying \
+                 again. <a href=\"/2023/day/1\">[Return to Day 1]</a>"
+            ),
+            Verdict::Wrong {
+                hint: Some("too high".to_string())
+            }
+        );
+        assert_eq!(
+            verdict(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            ),
+            Verdict::TooSoon {
+                wait_seconds: Some(65)
+            }
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+            Verdict::WrongLevel
+        );
+        assert!(Verdict::parse("<html>Something else</html>").is_err());
+    }
+
+    #[test]
+    fn submit_once() {
+        let server = TestServer::start(|request| {
+            let message = if request.body.contains("answer=42") {
+                "That's the right answer!"
+            } else {
+                "That's not the right answer; your answer is too low."
+            };
+            (200, page(message))
+        });
+        let client = Client::new(&Config {
+            session: Some("abc".to_string()),
+            base_url: server.base_url(),
+            year: 2023,
+            ..Config::default()
+        })
+        .unwrap();
+
+        let mut answers = Answers::default();
+        let mut history = History::default();
+        let cooldown = Duration::from_secs(60);
+        let start = UNIX_EPOCH + Duration::from_secs(1_701_388_800);
+        let mut submit = |answer, seconds| {
+            submit(
+                &client,
+                &mut answers,
+                &mut history,
+                (6, Part::Two),
+                answer,
+                start + Duration::from_secs(seconds),
+                cooldown,
+            )
+        };
+
+        assert_eq!(
+            submit("41", 0).unwrap(),
+            Verdict::Wrong {
+                hint: Some("too low".to_string())
+            }
+        );
+        assert_eq!(
+            submit("41", 100).unwrap_err().to_string(),
+            "41 was already submitted for day 6 part 2, and it was wrong"
+        );
+        assert_eq!(
+            submit("42", 30).unwrap_err().to_string(),
+            "the last answer was submitted too recently, wait 30s before submitting another"
+        );
+        assert_eq!(submit("42", 60).unwrap(), Verdict::Correct);
+        assert!(submit("43", 200).is_err());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2023/day/6/answer");
+        assert_eq!(requests[0].body, "level=2&answer=41");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+
+        assert_eq!(answers.get(6, &InputSource::Real, Part::Two), Some("42"));
+        assert!(answers.is_wrong(6, &InputSource::Real, Part::Two, "41"));
+        assert_eq!(history.submissions.len(), 2);
+        assert_eq!(
+            toml::from_str::<History>(&toml::to_string(&history).unwrap()).unwrap(),
+            history
+        );
+    }
+}
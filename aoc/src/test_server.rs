@@ -0,0 +1,85 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use tiny_http::{Header, Response, Server};
+
+/// A request the server received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A stand-in for the Advent of Code website, so that the client can be
+/// tested without the network.
+pub struct TestServer {
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<Request>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TestServer {
+    /// Starts a server on a free port that answers every request with
+    /// whatever status and body `respond` gives for it.
+    pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let thread = thread::spawn({
+            let server = Arc::clone(&server);
+            let requests = Arc::clone(&requests);
+            move || {
+                for mut incoming in server.incoming_requests() {
+                    let mut body = String::new();
+                    incoming.as_reader().read_to_string(&mut body).unwrap();
+                    let request = Request {
+                        method: incoming.method().to_string(),
+                        url: incoming.url().to_string(),
+                        cookie: incoming
+                            .headers()
+                            .iter()
+                            .find(|header| header.field.equiv("Cookie"))
+                            .map(|header| header.value.to_string()),
+                        body,
+                    };
+
+                    let (status, body) = respond(&request);
+                    requests.lock().unwrap().push(request);
+                    let content_type =
+                        Header::from_bytes("Content-Type", "text/plain; charset=utf-8").unwrap();
+                    let response = Response::from_string(body)
+                        .with_status_code(status)
+                        .with_header(content_type);
+                    incoming.respond(response).unwrap();
+                }
+            }
+        });
+
+        TestServer {
+            server,
+            requests,
+            thread: Some(thread),
+        }
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.server.server_addr())
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
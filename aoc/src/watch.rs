@@ -0,0 +1,241 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+
+/// How often to look for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// When every file that is watched was last modified.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// One part of `aoc run --format json`, as far as watching cares.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ns: Option<u64>,
+    pub status: String,
+    pub error: Option<String>,
+}
+
+/// Reruns a day's example tests and its real input whenever its source or
+/// inputs change, until it is interrupted.
+pub fn watch(root: &Path, day: u8) -> Result<()> {
+    let directory = root.join(format!("day-{day}"));
+    if !directory.is_dir() {
+        return Err(eyre!("{} does not exist", directory.display()));
+    }
+    let watched = [directory, root.join("aoc-core/src")];
+
+    let mut previous = None;
+    let mut snapshot = Snapshot::new();
+    loop {
+        let current = snapshot_of(&watched)?;
+        if current != snapshot {
+            snapshot = current;
+            println!("--- day {day} ---");
+            test(root, day)?;
+            match run(root, day) {
+                Ok(results) => {
+                    for line in diff(previous.as_deref(), &results) {
+                        println!("{line}");
+                    }
+                    previous = Some(results);
+                }
+                Err(error) => println!("{error:#}"),
+            }
+            println!("Watching for changes...");
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// When the files under each of `directories` were last modified, leaving
+/// out build output.
+fn snapshot_of(directories: &[PathBuf]) -> Result<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    let mut pending = directories.to_vec();
+    while let Some(directory) = pending.pop() {
+        let entries = fs::read_dir(&directory)
+            .wrap_err_with(|| format!("failed to read {}", directory.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                if entry.file_name() != "target" {
+                    pending.push(path);
+                }
+            } else {
+                snapshot.insert(path, metadata.modified()?);
+            }
+        }
+    }
+    Ok(snapshot)
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+/// Runs the day's tests, which solve the examples, and says how they went.
+fn test(root: &Path, day: u8) -> Result<()> {
+    let output = cargo(root)
+        .args(["test", "--quiet", "--package", &format!("day-{day}")])
+        .output()
+        .wrap_err("failed to run cargo test")?;
+
+    if output.status.success() {
+        println!("Examples: passed");
+    } else {
+        println!("Examples: failed");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // the failures are at the end, after the progress of every test
+        let lines = stdout.lines().chain(stderr.lines()).collect::<Vec<_>>();
+        for line in &lines[lines.len().saturating_sub(20)..] {
+            println!("  {line}");
+        }
+    }
+    Ok(())
+}
+
+/// Solves both parts of the day with the real input, with the day as it is
+/// now rather than as it was when this was built.
+fn run(root: &Path, day: u8) -> Result<Vec<PartRun>> {
+    let output = cargo(root)
+        .args(["run", "--quiet", "--release", "--package", "aoc", "--"])
+        .args(["run", "--day", &day.to_string(), "--format", "json"])
+        .output()
+        .wrap_err("failed to run cargo run")?;
+
+    serde_json::from_slice(&output.stdout).map_err(|_| {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eyre!("Real input: did not run\n{}", stderr.trim_end())
+    })
+}
+
+/// Describes each part's answer and time, along with how they changed since
+/// the `previous` run.
+fn diff(previous: Option<&[PartRun]>, current: &[PartRun]) -> Vec<String> {
+    current
+        .iter()
+        .map(|result| {
+            let before = previous
+                .into_iter()
+                .flatten()
+                .find(|before| before.part == result.part);
+            let mut line = format!("Part {}: ", result.part);
+
+            match (
+                &result.answer,
+                before.and_then(|before| before.answer.as_ref()),
+            ) {
+                (Some(answer), Some(old)) if answer != old => {
+                    line += &format!("{old} -> {answer}");
+                }
+                (Some(answer), _) => line += answer,
+                (None, _) => {
+                    let error = result.error.as_deref().unwrap_or_default();
+                    line += &format!("failed: {}", error.lines().next().unwrap_or_default());
+                }
+            }
+
+            if let Some(duration) = result.duration_ns.map(Duration::from_nanos) {
+                match before
+                    .and_then(|before| before.duration_ns)
+                    .map(Duration::from_nanos)
+                {
+                    Some(old) => line += &format!(" ({old:.2?} -> {duration:.2?})"),
+                    None => line += &format!(" ({duration:.2?})"),
+                }
+            }
+            if result.status != "unknown" && result.status != "failed" {
+                line += &format!(" [{}]", result.status);
+            }
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::watch::{diff, snapshot_of, PartRun};
+
+    fn result(part: u8, answer: Option<&str>, duration_ns: Option<u64>, status: &str) -> PartRun {
+        PartRun {
+            part,
+            answer: answer.map(str::to_string),
+            duration_ns,
+            status: status.to_string(),
+            error: answer
+                .is_none()
+                .then(|| "part 2 has not been solved yet".to_string()),
+        }
+    }
+
+    #[test]
+    fn answers_and_timings() {
+        let first = [
+            result(1, Some("374"), Some(1_500_000), "unknown"),
+            result(2, None, None, "failed"),
+        ];
+        assert_eq!(
+            diff(None, &first),
+            [
+                "Part 1: 374 (1.50ms)",
+                "Part 2: failed: part 2 has not been solved yet"
+            ]
+        );
+
+        let second = [
+            result(1, Some("374"), Some(900_000), "correct"),
+            result(2, Some("1030"), Some(2_000), "unknown"),
+        ];
+        assert_eq!(
+            diff(Some(&first), &second),
+            [
+                "Part 1: 374 (1.50ms -> 900.00µs) [correct]",
+                "Part 2: 1030 (2.00µs)"
+            ]
+        );
+
+        let third = [result(2, Some("8410"), Some(2_000), "incorrect")];
+        assert_eq!(
+            diff(Some(&second), &third),
+            ["Part 2: 1030 -> 8410 (2.00µs -> 2.00µs) [incorrect]"]
+        );
+    }
+
+    #[test]
+    fn changes() {
+        let directory = tempfile::tempdir().unwrap();
+        let watched = [directory.path().join("day-1")];
+        let day = &watched[0];
+        fs::create_dir_all(day.join("src")).unwrap();
+        fs::create_dir_all(day.join("target")).unwrap();
+        fs::write(day.join("src/lib.rs"), "").unwrap();
+        fs::write(day.join("target/out"), "").unwrap();
+
+        let snapshot = snapshot_of(&watched).unwrap();
+        assert_eq!(
+            snapshot.keys().collect::<Vec<_>>(),
+            [&day.join("src/lib.rs")]
+        );
+
+        fs::write(day.join("real-input.txt"), "1abc2").unwrap();
+        assert_ne!(snapshot_of(&watched).unwrap(), snapshot);
+    }
+}
@@ -0,0 +1,548 @@
+use std::{
+    cmp::Reverse,
+    str::{self, Utf8Error},
+};
+
+use aoc_core::{
+    answer::CheckedIterator,
+    parsing::{ParseMode, ParseResult},
+    Generate, Solution,
+};
+use eyre::{eyre, Result, WrapErr};
+use rand::{seq::SliceRandom, Rng};
+
+type Output = u32;
+type Input = Document;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 142;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 281;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        Ok(("", input.lines().collect()))
+    }
+
+    /// Keeps the lines that are not valid UTF-8, so that they can be listed.
+    fn parse_bytes(input: &[u8], _mode: ParseMode) -> Result<Input> {
+        Ok(Document::from_bytes(input))
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        input.calibration_sum(&Vocabulary::numerals())
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        input.calibration_sum(&Vocabulary::english())
+    }
+}
+
+/// The digits spelled out in English, with their values.
+pub const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// The words that digits can be spelled out as, besides their numerals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    case_insensitive: bool,
+}
+
+impl Vocabulary {
+    /// Only numerals.
+    pub fn numerals() -> Self {
+        Vocabulary::default()
+    }
+
+    /// "one" to "nine".
+    pub fn english() -> Self {
+        Vocabulary::new(ENGLISH).unwrap()
+    }
+
+    pub fn new<W: Into<String>>(words: impl IntoIterator<Item = (W, u32)>) -> Result<Self> {
+        let mut vocabulary = Vocabulary::default();
+        for (word, value) in words {
+            vocabulary = vocabulary.with_word(word, value)?;
+        }
+        Ok(vocabulary)
+    }
+
+    /// The words for one to nine, in order, separated by whitespace.
+    pub fn from_list(list: &str) -> Result<Self> {
+        let words = list.split_whitespace().collect::<Vec<_>>();
+        if words.len() != 9 {
+            return Err(eyre!(
+                "expected the words for one to nine, found {} words",
+                words.len()
+            ));
+        }
+        Vocabulary::new(words.into_iter().zip(1..))
+    }
+
+    /// A word and its digit on every line, like `"eins 1"`.
+    pub fn from_lines(text: &str) -> Result<Self> {
+        let mut vocabulary = Vocabulary::default();
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [] => {}
+                [word, value] => {
+                    let value = value
+                        .parse()
+                        .map_err(|_| eyre!("line {line_number}: {value:?} is not a digit"))?;
+                    vocabulary = vocabulary
+                        .with_word(word, value)
+                        .wrap_err_with(|| format!("line {line_number}"))?;
+                }
+                _ => {
+                    return Err(eyre!(
+                        "line {line_number}: expected a word and a digit, found {line:?}"
+                    ))
+                }
+            }
+        }
+        Ok(vocabulary)
+    }
+
+    /// Adds another word for a digit, like "zero" or "null" for 0.
+    pub fn with_word(mut self, word: impl Into<String>, value: u32) -> Result<Self> {
+        let word = word.into();
+        if value > 9 {
+            return Err(eyre!("{word:?} is {value}, which is not a digit"));
+        }
+        if word.is_empty() || word.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(eyre!("{word:?} cannot be a word for a digit"));
+        }
+        self.words.push((word, value));
+        Ok(self)
+    }
+
+    /// Matches the words whatever their case.
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// The longest word at the start of `text`, and how many bytes it takes.
+    fn word_at(&self, text: &str) -> Option<(u32, usize)> {
+        longest(self.words.iter().filter_map(|(word, value)| {
+            let length = if self.case_insensitive {
+                matched_length(text.chars(), word.chars())
+            } else {
+                text.starts_with(word.as_str()).then_some(word.len())
+            };
+            length.map(|length| (*value, length))
+        }))
+    }
+
+    /// The longest word at the end of `text`, and how many bytes it takes.
+    fn word_before(&self, text: &str) -> Option<(u32, usize)> {
+        longest(self.words.iter().filter_map(|(word, value)| {
+            let length = if self.case_insensitive {
+                matched_length(text.chars().rev(), word.chars().rev())
+            } else {
+                text.ends_with(word.as_str()).then_some(word.len())
+            };
+            length.map(|length| (*value, length))
+        }))
+    }
+}
+
+/// The longest of the `matches`, or the first of the longest ones.
+fn longest(matches: impl Iterator<Item = (u32, usize)>) -> Option<(u32, usize)> {
+    matches.min_by_key(|&(_, length)| Reverse(length))
+}
+
+/// How many bytes of `text` match `word` when ignoring case, which is not
+/// always the length of the word.
+fn matched_length(
+    mut text: impl Iterator<Item = char>,
+    word: impl Iterator<Item = char>,
+) -> Option<usize> {
+    let mut length = 0;
+    for expected in word {
+        let c = text.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+        length += c.len_utf8();
+    }
+    Some(length)
+}
+
+/// A numeral or spelled out digit in a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a> {
+    pub value: u32,
+    /// Where the digit starts in the line, in bytes.
+    pub position: usize,
+    /// The digit as it is written, like `"7"` or `"seven"`.
+    pub text: &'a str,
+}
+
+impl Match<'_> {
+    pub fn is_spelled(&self) -> bool {
+        !self.text.starts_with(|c: char| c.is_ascii_digit())
+    }
+}
+
+/// The first and last digits of a line, which make up its calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    pub first: Match<'a>,
+    pub last: Match<'a>,
+}
+
+impl<'a> Calibration<'a> {
+    /// Looks for the last digit from the end, so that the "one" of "twone"
+    /// is found.
+    pub fn find(line: &'a str, vocabulary: &Vocabulary) -> Option<Self> {
+        Some(Calibration {
+            first: first_digit(line, vocabulary)?,
+            last: last_digit(line, vocabulary)?,
+        })
+    }
+
+    pub fn value(&self) -> u32 {
+        self.first.value * 10 + self.last.value
+    }
+}
+
+/// The lines of a calibration document, which may not be valid UTF-8.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    lines: Vec<Result<String, Utf8Error>>,
+}
+
+impl Document {
+    /// Trims the lines like [`Solution::parse`] does.
+    pub fn from_bytes(document: &[u8]) -> Self {
+        let document = document.trim_ascii_end();
+        if document.is_empty() {
+            return Document::default();
+        }
+        let lines = document
+            .split(|&byte| byte == b'\n')
+            .map(|line| {
+                str::from_utf8(line.trim_ascii_end()).map(|line| line.trim_end().to_string())
+            })
+            .collect();
+        Document { lines }
+    }
+
+    /// The calibration of every line, listing all of the lines that have no
+    /// digits or are not valid UTF-8 in the error.
+    pub fn report(&self, vocabulary: &Vocabulary) -> Result<Report<'_>> {
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| line.as_deref().map_err(|&error| error));
+        report(calibrate(lines, vocabulary)?)
+    }
+
+    /// The sum of the calibration values of every line.
+    pub fn calibration_sum(&self, vocabulary: &Vocabulary) -> Result<Output> {
+        self.report(vocabulary).map(|report| report.total)
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Document {
+    fn from_iter<I: IntoIterator<Item = S>>(lines: I) -> Self {
+        let lines = lines.into_iter().map(|line| Ok(line.into())).collect();
+        Document { lines }
+    }
+}
+
+/// The calibration of every line of a document, and what they add up to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<'a> {
+    /// The calibration of each line, in order.
+    pub calibrations: Vec<Calibration<'a>>,
+    pub total: Output,
+}
+
+/// Like [`Document::report`], for valid UTF-8.
+pub fn calibration_report<'a, S: AsRef<str>>(
+    lines: &'a [S],
+    vocabulary: &Vocabulary,
+) -> Result<Report<'a>> {
+    report(calibrate(
+        lines.iter().map(|line| Ok(line.as_ref())),
+        vocabulary,
+    )?)
+}
+
+/// Like [`Document::calibration_sum`], for valid UTF-8.
+pub fn calibration_sum<S: AsRef<str>>(lines: &[S], vocabulary: &Vocabulary) -> Result<Output> {
+    calibration_report(lines, vocabulary).map(|report| report.total)
+}
+
+fn report(calibrations: Vec<Calibration>) -> Result<Report> {
+    let total = calibrations.iter().map(Calibration::value).checked_sum()?;
+    Ok(Report {
+        calibrations,
+        total,
+    })
+}
+
+fn calibrate<'a>(
+    lines: impl Iterator<Item = Result<&'a str, Utf8Error>>,
+    vocabulary: &Vocabulary,
+) -> Result<Vec<Calibration<'a>>> {
+    let mut calibrations = Vec::new();
+    let mut problems = Vec::new();
+    for (index, line) in lines.enumerate() {
+        match line.map(|line| Calibration::find(line, vocabulary)) {
+            Ok(Some(calibration)) => calibrations.push(calibration),
+            Ok(None) => problems.push(format!("line {}: no digits", index + 1)),
+            Err(error) => problems.push(format!(
+                "line {}: invalid UTF-8 at byte {}",
+                index + 1,
+                error.valid_up_to()
+            )),
+        }
+    }
+
+    match problems.len() {
+        0 => Ok(calibrations),
+        1 => Err(eyre!("{}", problems[0])),
+        count => Err(eyre!(
+            "{count} lines cannot be calibrated:\n{}",
+            problems.join("\n")
+        )),
+    }
+}
+
+fn numeral(c: char) -> Option<u32> {
+    c.is_ascii_digit().then(|| c.to_digit(10).unwrap())
+}
+
+fn first_digit<'a>(line: &'a str, vocabulary: &Vocabulary) -> Option<Match<'a>> {
+    line.char_indices().find_map(|(start, c)| {
+        let (value, length) = match numeral(c) {
+            Some(value) => (value, 1),
+            None => vocabulary.word_at(&line[start..])?,
+        };
+        Some(Match {
+            value,
+            position: start,
+            text: &line[start..start + length],
+        })
+    })
+}
+
+fn last_digit<'a>(line: &'a str, vocabulary: &Vocabulary) -> Option<Match<'a>> {
+    line.char_indices().rev().find_map(|(start, c)| {
+        let end = start + c.len_utf8();
+        let (value, length) = match numeral(c) {
+            Some(value) => (value, 1),
+            None => vocabulary.word_before(&line[..end])?,
+        };
+        Some(Match {
+            value,
+            position: end - length,
+            text: &line[end - length..end],
+        })
+    })
+}
+
+impl Generate for Day1 {
+    /// Makes `size` lines, each with at least one digit.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut line = String::new();
+                let length = rng.gen_range(1..=20);
+                while line.len() < length {
+                    match rng.gen_range(0..10) {
+                        0..=5 => line.push(rng.gen_range('a'..='z')),
+                        6 | 7 => line.push(rng.gen_range('1'..='9')),
+                        _ => line.push_str(ENGLISH.choose(rng).unwrap().0),
+                    }
+                }
+                if !line.contains(|c: char| c.is_ascii_digit()) {
+                    let index = rng.gen_range(0..=line.len());
+                    line.insert(index, rng.gen_range('1'..='9'));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::check_examples;
+
+    use crate::{
+        calibration_report, calibration_sum, Calibration, Day1, Document, Match, Vocabulary,
+    };
+
+    #[test]
+    fn examples() {
+        check_examples::<Day1>().unwrap();
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let english = Vocabulary::english();
+        let calibration = |line| Calibration::find(line, &english).unwrap();
+
+        assert_eq!(
+            calibration("xtwone3four"),
+            Calibration {
+                first: Match {
+                    value: 2,
+                    position: 1,
+                    text: "two",
+                },
+                last: Match {
+                    value: 4,
+                    position: 7,
+                    text: "four",
+                },
+            }
+        );
+        assert_eq!(calibration("twone").value(), 21);
+        assert_eq!(calibration("eightwo").value(), 82);
+        assert_eq!(calibration("oneight").last.position, 2);
+
+        let single = calibration("ab7cd");
+        assert_eq!(single.first, single.last);
+        assert!(!single.first.is_spelled());
+        assert_eq!(single.value(), 77);
+
+        assert_eq!(Calibration::find("twone", &Vocabulary::numerals()), None);
+    }
+
+    #[test]
+    fn vocabularies() {
+        let german = Vocabulary::from_list("eins zwei drei vier fünf sechs sieben acht neun")
+            .unwrap()
+            .with_word("null", 0)
+            .unwrap();
+        let value = |line, vocabulary: &Vocabulary| {
+            Calibration::find(line, vocabulary).map(|calibration| calibration.value())
+        };
+
+        assert_eq!(value("xnullfünfzig", &german), Some(5));
+        assert_eq!(value("achtzehn4null", &german), Some(80));
+        assert_eq!(value("FÜNFundNEUN", &german), None);
+
+        let german = german.case_insensitive();
+        assert_eq!(value("FÜNFundNEUN", &german), Some(59));
+        let calibration = Calibration::find("sechsUNDZWANZIG: ZWEI", &german).unwrap();
+        assert_eq!(calibration.last.text, "ZWEI");
+        assert_eq!(calibration.last.position, 17);
+
+        // the Kelvin sign is an uppercase "k" that takes up three bytes
+        let kein = Vocabulary::new([("kein", 0)]).unwrap().case_insensitive();
+        let calibration = Calibration::find("\u{212a}ein", &kein).unwrap();
+        assert_eq!(calibration.first.text, "\u{212a}ein");
+        assert_eq!(calibration.last.position, 0);
+
+        let spanish = Vocabulary::from_list("uno dos tres cuatro cinco seis siete ocho nueve")
+            .unwrap()
+            .with_word("cero", 0)
+            .unwrap();
+        assert_eq!(value("dosceroxx", &spanish), Some(20));
+
+        assert!(Vocabulary::from_list("one two").is_err());
+        assert!(Vocabulary::new([("ten", 10)]).is_err());
+        assert!(Vocabulary::new([("", 1)]).is_err());
+    }
+
+    #[test]
+    fn longest_word() {
+        for words in ["one 1\noneteen 9\n\nteen 5\n", "teen 5\noneteen 9\none 1"] {
+            let vocabulary = Vocabulary::from_lines(words).unwrap();
+            let calibration = Calibration::find("xoneteen", &vocabulary).unwrap();
+            assert_eq!(calibration.first.text, "oneteen");
+            assert_eq!(calibration.last.text, "oneteen");
+            assert_eq!(calibration.value(), 99);
+        }
+
+        let error = |words| format!("{:#}", Vocabulary::from_lines(words).unwrap_err());
+        assert_eq!(
+            error("one 1\ntwo"),
+            "line 2: expected a word and a digit, found \"two\""
+        );
+        assert_eq!(error("one x"), "line 1: \"x\" is not a digit");
+        assert_eq!(
+            error("ten 10"),
+            "line 1: \"ten\" is 10, which is not a digit"
+        );
+    }
+
+    #[test]
+    fn every_problem() {
+        let english = Vocabulary::english();
+
+        let lines = ["a1", "b", "ünïcödé 2 ☃", "", "three"];
+        assert_eq!(
+            calibration_sum(&lines, &english).unwrap_err().to_string(),
+            "2 lines cannot be calibrated:\nline 2: no digits\nline 4: no digits"
+        );
+        assert_eq!(
+            calibration_sum(&["x", "1"], &english)
+                .unwrap_err()
+                .to_string(),
+            "line 1: no digits"
+        );
+
+        let document = b"1abc2\r\nx\xffseven\nnix\n\xc3\n2\n";
+        assert_eq!(
+            Document::from_bytes(document)
+                .calibration_sum(&english)
+                .unwrap_err()
+                .to_string(),
+            "3 lines cannot be calibrated:\nline 2: invalid UTF-8 at byte 1\n\
+             line 3: no digits\nline 4: invalid UTF-8 at byte 0"
+        );
+        let document = Document::from_bytes("1abc2 \r\nfünf 7 ☃\n\n".as_bytes());
+        assert_eq!(document, ["1abc2", "fünf 7 ☃"].into_iter().collect());
+        assert_eq!(document.calibration_sum(&english).unwrap(), 89);
+        assert_eq!(
+            Document::from_bytes(b"\n")
+                .calibration_sum(&english)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn report() {
+        let lines = ["two1nine", "abc7", "xtwone3four"];
+        let report = calibration_report(&lines, &Vocabulary::english()).unwrap();
+        assert_eq!(report.total, 29 + 77 + 24);
+        assert_eq!(
+            report
+                .calibrations
+                .iter()
+                .map(Calibration::value)
+                .collect::<Vec<_>>(),
+            [29, 77, 24]
+        );
+        assert!(report.calibrations[0].first.is_spelled());
+        assert_eq!(report.calibrations[2].last.position, 7);
+
+        let numerals = calibration_report(&lines, &Vocabulary::numerals()).unwrap();
+        assert_eq!(numerals.total, 11 + 77 + 33);
+    }
+}
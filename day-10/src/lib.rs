@@ -0,0 +1,470 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Display, Formatter},
+};
+
+use aoc_core::{
+    answer::checked_cast,
+    grid::{grid, Grid, Position},
+    parsing::ParseResult,
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use rand::{seq::SliceRandom, Rng};
+use tracing::trace;
+
+type Output = u32;
+type Input = Grid<Cell>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 8;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 10;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        grid(|c| match c {
+            '|' => Ok(Cell::NS),
+            '-' => Ok(Cell::WE),
+            'L' => Ok(Cell::NE),
+            'J' => Ok(Cell::NW),
+            '7' => Ok(Cell::SW),
+            'F' => Ok(Cell::SE),
+            '.' => Ok(Cell::Gr),
+            'S' => Ok(Cell::St),
+            _ => Err(format!("{c:?} is not a pipe")),
+        })(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        let starting_position = find_start(&input)?;
+
+        let mut queue = VecDeque::new();
+        let mut min_distances = HashMap::<Position, u32>::new();
+        for position in input.neighbors4(starting_position) {
+            if get_neighbors(&input, position).any(|neighbor| neighbor == starting_position) {
+                min_distances.insert(position, 1);
+                queue.push_back((position, 1));
+            }
+        }
+
+        while let Some(to_search) = queue.pop_front() {
+            let (position, current_cell_distance) = to_search;
+
+            for neighbor in get_neighbors(&input, position) {
+                if neighbor != starting_position
+                    && min_distances
+                        .get(&neighbor)
+                        .is_none_or(|d| *d >= current_cell_distance)
+                {
+                    min_distances.insert(neighbor, current_cell_distance + 1);
+                    queue.push_back((neighbor, current_cell_distance + 1));
+                }
+            }
+        }
+
+        min_distances
+            .values()
+            .copied()
+            .max()
+            .ok_or_else(|| eyre!("no pipes connect to the start"))
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        let mut bigger_cells = make_bigger(&input);
+
+        let starting_position = find_start(&bigger_cells)?;
+
+        // remove stragglers from starting position
+        for direction in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            let Some(position) = bigger_cells.step(starting_position, direction) else {
+                continue;
+            };
+
+            // check if the next cell is out of bounds
+            let Some(next_position) = bigger_cells.step(position, direction) else {
+                bigger_cells[position] = Cell::IG;
+                continue;
+            };
+            let next_cell = bigger_cells[next_position];
+
+            let (dx, dy) = direction;
+            if !((dx == 1 || dx == -1) && next_cell == Cell::WE
+                || (dy == 1 || dy == -1) && next_cell == Cell::NS)
+            {
+                bigger_cells[position] = Cell::IG;
+            }
+        }
+
+        let main_loop_cells = find_main_loop(starting_position, &bigger_cells);
+        if main_loop_cells.len() == 1 {
+            return Err(eyre!("no pipes connect to the start"));
+        }
+
+        // remake bigger_cells but make non-main loop cells Gr
+        let input_cells_again = input.map(|(x, y), &cell| {
+            if main_loop_cells.contains(&(x * 3 + 1, y * 3 + 1)) {
+                cell
+            } else {
+                Cell::Gr
+            }
+        });
+        let bigger_cells = make_bigger(&input_cells_again);
+
+        trace!("bigger cells:\n{}", bigger_cells);
+
+        let x_length = bigger_cells.width();
+        let y_length = bigger_cells.height();
+        // all the edge cells of the map
+        let edges = bigger_cells
+            .positions()
+            .filter(|&(x, y)| x == 0 || y == 0 || x == x_length - 1 || y == y_length - 1)
+            .collect::<Vec<_>>();
+
+        let mut outside_visited = HashSet::<Position>::new();
+        let mut outside_queue = VecDeque::with_capacity(1024);
+        let mut outside_introduced_ground_map = HashSet::<Position>::new();
+        let mut outside_introduced_ground_count = 0;
+
+        for position in edges {
+            outside_queue.push_back(position);
+        }
+
+        while let Some(position) = outside_queue.pop_back() {
+            if outside_visited.contains(&position) {
+                continue;
+            }
+            if !main_loop_cells.contains(&position) {
+                outside_visited.insert(position);
+            }
+
+            if bigger_cells[position] == Cell::IG {
+                outside_introduced_ground_map.insert(position);
+                outside_introduced_ground_count += 1;
+            }
+
+            for neighbor in bigger_cells.neighbors4(position) {
+                if !outside_visited.contains(&neighbor) && !main_loop_cells.contains(&neighbor) {
+                    outside_queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let outside_visited_count = outside_visited.len();
+        let outside_cells = (outside_visited_count - outside_introduced_ground_count) / 9;
+
+        let actual_area = input.width() * input.height();
+        let inside = actual_area - outside_cells - main_loop_cells.len() / 3;
+        checked_cast(inside)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    NS, // |
+    WE, // -
+    NE, // L
+    NW, // J
+    SW, // 7
+    SE, // F
+    Gr, // .
+    St, // S
+
+    IG, // introduced ground (" ")
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::NS => '|',
+            Cell::WE => '-',
+            Cell::NE => 'L',
+            Cell::NW => 'J',
+            Cell::SW => '7',
+            Cell::SE => 'F',
+            Cell::Gr => '.',
+            Cell::St => 'S',
+            Cell::IG => ' ',
+        };
+        write!(f, "{c}")
+    }
+}
+
+fn find_start(cells: &Grid<Cell>) -> Result<Position> {
+    cells
+        .position_of(&Cell::St)
+        .ok_or_else(|| eyre!("there is no start"))
+}
+
+/// The tiles the pipe at `position` connects to, leaving out the ones that
+/// would be off the edge of the grid. Anything that is not a pipe connects
+/// to nothing.
+fn get_neighbors(cells: &Grid<Cell>, position: Position) -> impl Iterator<Item = Position> + '_ {
+    let directions: &[(isize, isize)] = match cells[position] {
+        Cell::NS => &[(0, -1), (0, 1)],
+        Cell::WE => &[(-1, 0), (1, 0)],
+        Cell::NE => &[(0, -1), (1, 0)],
+        Cell::NW => &[(-1, 0), (0, -1)],
+        Cell::SW => &[(-1, 0), (0, 1)],
+        Cell::SE => &[(0, 1), (1, 0)],
+        Cell::Gr | Cell::St | Cell::IG => &[],
+    };
+    directions
+        .iter()
+        .filter_map(move |&direction| cells.step(position, direction))
+}
+
+#[rustfmt::skip]
+fn enlarge_cell(cell: Cell) -> &'static [Cell; 9] {
+    use Cell as C;
+    match cell {
+        C::St => &[
+            C::IG, C::NS, C::IG,
+            C::WE, C::St, C::WE,
+            C::IG, C::NS, C::IG,
+        ],
+        C::NS => &[
+            C::IG, C::NS, C::IG,
+            C::IG, C::NS, C::IG,
+            C::IG, C::NS, C::IG,
+        ],
+        C::WE => &[
+            C::IG, C::IG, C::IG,
+            C::WE, C::WE, C::WE,
+            C::IG, C::IG, C::IG,
+        ],
+        C::NE => &[
+            C::IG, C::NS, C::IG,
+            C::IG, C::NE, C::WE,
+            C::IG, C::IG, C::IG,
+        ],
+        C::NW => &[
+            C::IG, C::NS, C::IG,
+            C::WE, C::NW, C::IG,
+            C::IG, C::IG, C::IG,
+        ],
+        C::SW => &[
+            C::IG, C::IG, C::IG,
+            C::WE, C::SW, C::IG,
+            C::IG, C::NS, C::IG,
+        ],
+        C::SE => &[
+            C::IG, C::IG, C::IG,
+            C::IG, C::SE, C::WE,
+            C::IG, C::NS, C::IG,
+        ],
+        C::Gr => &[
+            C::Gr, C::Gr, C::Gr,
+            C::Gr, C::Gr, C::Gr,
+            C::Gr, C::Gr, C::Gr,
+        ],
+        C::IG => panic!("Invalid cell")
+    }
+}
+
+fn find_main_loop(starting_position: Position, cells: &Grid<Cell>) -> HashSet<Position> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    visited.insert(starting_position);
+
+    for (dx, dy) in [(3, 0), (-3, 0), (0, 3), (0, -3)] {
+        let Some(position) = cells.step(starting_position, (dx, dy)) else {
+            continue;
+        };
+
+        // this position is the node next to the starting position in the untransformed map
+        let cell = cells[position];
+        if (cell == Cell::NS && (dy == -3 || dy == 3))
+            || (cell == Cell::WE && (dx == -3 || dx == 3))
+            || (cell == Cell::NW && (dy == 3 || dx == 3))
+            || (cell == Cell::NE && (dy == 3 || dx == -3))
+            || (cell == Cell::SW && (dy == -3 || dx == 3))
+            || (cell == Cell::SE && (dy == -3 || dx == -3))
+        {
+            queue.extend(cells.step(starting_position, (dx / 3, dy / 3)));
+        }
+    }
+
+    while let Some(to_search) = queue.pop_back() {
+        for neighbor in get_neighbors(cells, to_search) {
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    visited
+}
+
+// the ways a generated pipe connects, as bits
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+impl Generate for Day10 {
+    /// Makes a field `size` tiles wide and high, with a single loop through it
+    /// and junk pipes everywhere else. The loop is the outline of a random
+    /// blob of squares, with a tile at every corner of a square, so the tiles
+    /// inside the blob are inside the loop.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let squares = size.max(2) - 1;
+        let mut blob = Grid::from_fn(squares, squares, |_| false);
+        let mut added = vec![(rng.gen_range(0..squares), rng.gen_range(0..squares))];
+        blob[added[0]] = true;
+
+        // grow the blob one square at a time, for as long as it keeps finding
+        // squares that leave it an outline that is a single loop
+        let target = rng.gen_range(1..=squares * squares);
+        for _ in 0..100 * squares * squares {
+            if added.len() == target {
+                break;
+            }
+            let square = *added.choose(rng).unwrap();
+            let free = blob
+                .neighbors4(square)
+                .filter(|&neighbor| !blob[neighbor])
+                .collect::<Vec<_>>();
+            if let Some(&neighbor) = free.choose(rng) {
+                if keeps_one_outline(&blob, neighbor) {
+                    blob[neighbor] = true;
+                    added.push(neighbor);
+                }
+            }
+        }
+
+        // trace the outline, where a square meets one outside of the blob
+        let mut pipes = Grid::from_fn(squares + 1, squares + 1, |_| 0);
+        let outside = |(x, y): Position, (dx, dy): (isize, isize)| {
+            blob.step((x, y), (dx, dy))
+                .is_none_or(|neighbor| !blob[neighbor])
+        };
+        for &(x, y) in &added {
+            if outside((x, y), (0, -1)) {
+                pipes[(x, y)] |= EAST;
+                pipes[(x + 1, y)] |= WEST;
+            }
+            if outside((x, y), (0, 1)) {
+                pipes[(x, y + 1)] |= EAST;
+                pipes[(x + 1, y + 1)] |= WEST;
+            }
+            if outside((x, y), (-1, 0)) {
+                pipes[(x, y)] |= SOUTH;
+                pipes[(x, y + 1)] |= NORTH;
+            }
+            if outside((x, y), (1, 0)) {
+                pipes[(x + 1, y)] |= SOUTH;
+                pipes[(x + 1, y + 1)] |= NORTH;
+            }
+        }
+
+        let outline = pipes
+            .iter()
+            .filter(|(_, &pipe)| pipe != 0)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        let start = *outline.choose(rng).unwrap();
+        let rows = (0..=squares).map(|y| {
+            (0..=squares)
+                .map(|x| match pipes[(x, y)] {
+                    _ if (x, y) == start => 'S',
+                    pipe if pipe == NORTH | SOUTH => '|',
+                    pipe if pipe == EAST | WEST => '-',
+                    pipe if pipe == NORTH | EAST => 'L',
+                    pipe if pipe == NORTH | WEST => 'J',
+                    pipe if pipe == SOUTH | WEST => '7',
+                    pipe if pipe == SOUTH | EAST => 'F',
+                    // junk next to the start could look like it is connected
+                    _ if x.abs_diff(start.0) + y.abs_diff(start.1) == 1 => '.',
+                    _ => *b"|-LJ7F....".choose(rng).unwrap() as char,
+                })
+                .collect::<String>()
+        });
+        rows.collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Whether adding `square` to the blob leaves its outline a single loop,
+/// which it does unless it makes a hole in the blob, or makes two squares of
+/// it touch only at a corner.
+fn keeps_one_outline(blob: &Grid<bool>, square: Position) -> bool {
+    // the squares around this one, in order, starting with one on a side
+    const AROUND: [(isize, isize); 8] = [
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+    ];
+    let in_blob = AROUND.map(|offset| blob.step(square, offset).is_some_and(|square| blob[square]));
+
+    // a square of the blob at a corner, with neither of the squares on the
+    // sides next to it in the blob, would only touch this one at the corner
+    let touches_at_corner = (1..8)
+        .step_by(2)
+        .any(|corner| in_blob[corner] && !in_blob[corner - 1] && !in_blob[(corner + 1) % 8]);
+    // the blob is in one piece, so if it is around this square in more than
+    // one run, filling the square would close a hole between them
+    let runs = (0..8)
+        .filter(|&index| in_blob[index] && !in_blob[(index + 7) % 8])
+        .count();
+
+    !touches_at_corner && runs == 1
+}
+
+/// Blows every cell up into 3x3 cells, so that the gaps between pipes become
+/// cells of their own.
+fn make_bigger(input: &Input) -> Grid<Cell> {
+    Grid::from_fn(input.width() * 3, input.height() * 3, |(x, y)| {
+        enlarge_cell(input[(x / 3, y / 3)])[(y % 3) * 3 + x % 3]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use crate::Day10;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day10>().unwrap();
+    }
+
+    /// The farthest tile of the smallest loop is the one across from the
+    /// start, not one next to it reached the long way around.
+    #[test]
+    fn smallest_loop() {
+        let input = Day10::parse("F7.\nLS.\n...", ParseMode::Strict).unwrap();
+        assert_eq!(Day10::solve_part1(input).unwrap(), 2);
+    }
+
+    /// A pipe that points off the edge of the field connects to nothing there.
+    #[test]
+    fn off_the_edge() {
+        let input = Day10::parse("S-J\n...", ParseMode::Strict).unwrap();
+        assert_eq!(Day10::solve_part1(input.clone()).unwrap(), 2);
+        assert!(Day10::solve_part2(input).is_ok());
+    }
+
+    #[test]
+    fn no_loop() {
+        for (input, error) in [
+            ("...\n...", "there is no start"),
+            ("S.|\n-..", "no pipes connect to the start"),
+        ] {
+            let input = Day10::parse(input, ParseMode::Strict).unwrap();
+            for part in [Day10::solve_part1, Day10::solve_part2] {
+                assert_eq!(part(input.clone()).unwrap_err().to_string(), error);
+            }
+        }
+    }
+}
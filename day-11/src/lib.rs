@@ -0,0 +1,211 @@
+use std::fmt::{self, Display, Formatter};
+
+use aoc_core::{
+    answer::{checked_cast, CheckedIterator},
+    grid::{grid, Grid, Position},
+    parsing::ParseResult,
+    Generate, Solution, Unsolved,
+};
+use eyre::Result;
+use rand::Rng;
+use tracing::trace;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Galaxy,
+    Empty,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Galaxy => write!(f, "#"),
+            Cell::Empty => write!(f, "."),
+        }
+    }
+}
+
+type Output = u64;
+type Input = Grid<Cell>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 374;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 10;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        grid(|c| match c {
+            '.' => Ok(Cell::Empty),
+            '#' => Ok(Cell::Galaxy),
+            _ => Err(format!("{c:?} is not a galaxy or empty space")),
+        })(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        distance_sum(&input, 2)
+    }
+
+    fn solve_part2(_input: Input) -> Result<Output> {
+        Err(Unsolved.into())
+    }
+}
+
+impl Generate for Day11 {
+    /// Makes an image `size` pixels wide and high, where a random share of
+    /// up to a tenth of the pixels are galaxies, so that some rows and
+    /// columns are empty.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let density = rng.gen_range(0.0..0.1);
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.gen_bool(density) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The sum of the distances between every pair of galaxies, once every empty
+/// row and column has turned into `expansion` of them. Instead of inserting
+/// the rows and columns, each galaxy is moved by the empty ones before it.
+fn distance_sum(input: &Input, expansion: usize) -> Result<Output> {
+    // how far the empty rows or columns before each one move it
+    let shifts = |empty: Vec<bool>| {
+        empty
+            .into_iter()
+            .scan(0, |shift, empty| {
+                let current = *shift;
+                if empty {
+                    *shift += expansion - 1;
+                }
+                Some(current)
+            })
+            .collect::<Vec<_>>()
+    };
+    let row_shifts = shifts(
+        input
+            .rows()
+            .map(|row| row.iter().all(|&c| c == Cell::Empty))
+            .collect(),
+    );
+    let column_shifts = shifts(
+        input
+            .columns()
+            .map(|mut column| column.all(|&c| c == Cell::Empty))
+            .collect(),
+    );
+
+    let all_galaxies = input
+        .iter()
+        .filter(|(_, &cell)| cell == Cell::Galaxy)
+        .map(|((x, y), _)| (x + column_shifts[x], y + row_shifts[y]))
+        .collect::<Vec<_>>();
+    trace!("galaxies after expanding: {:?}", all_galaxies);
+
+    // every pair of galaxies, counted once
+    let distance_sum = all_galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &galaxy_coordinate)| {
+            all_galaxies[index + 1..]
+                .iter()
+                .map(move |&coordinate| manhattan_distance(galaxy_coordinate, coordinate))
+        })
+        .checked_sum()?;
+    checked_cast(distance_sum)
+}
+
+/// [`distance_sum`] with every empty row and column doubled, the slow way,
+/// by inserting them into the image.
+#[cfg(test)]
+fn distance_sum_by_expanding(input: Input) -> Result<Output> {
+    let mut working_input = input.clone();
+
+    // insert the new empty rows and columns back to front, so that the
+    // indices of the ones still to be inserted do not move
+    let empty_rows = input
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().all(|&c| c == Cell::Empty))
+        .map(|(row_index, _)| row_index)
+        .collect::<Vec<_>>();
+    for &row_index in empty_rows.iter().rev() {
+        working_input.insert_row(row_index, Cell::Empty);
+    }
+
+    let empty_columns = input
+        .columns()
+        .enumerate()
+        .filter_map(|(column_index, mut column)| {
+            column.all(|&c| c == Cell::Empty).then_some(column_index)
+        })
+        .collect::<Vec<_>>();
+    for &column_index in empty_columns.iter().rev() {
+        working_input.insert_column(column_index, Cell::Empty);
+    }
+
+    let all_galaxies = working_input
+        .iter()
+        .filter(|(_, &cell)| cell == Cell::Galaxy)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+
+    // every pair of galaxies, counted once
+    let distance_sum = all_galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &galaxy_coordinate)| {
+            all_galaxies[index + 1..]
+                .iter()
+                .map(move |&coordinate| manhattan_distance(galaxy_coordinate, coordinate))
+        })
+        .checked_sum()?;
+    checked_cast(distance_sum)
+}
+
+fn manhattan_distance((x1, y1): Position, (x2, y2): Position) -> usize {
+    x1.abs_diff(x2) + y1.abs_diff(y2)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_example, grid::Grid, Part};
+    use proptest::{collection::vec, prelude::*};
+
+    use crate::{distance_sum, distance_sum_by_expanding, Cell, Day11};
+
+    #[test]
+    fn examples() {
+        // part 2 is unsolved, and test-input-2.txt is not one of its examples
+        check_example::<Day11>(Part::One).unwrap();
+    }
+
+    proptest! {
+        #[test]
+        fn shifting_matches_expanding(
+            rows in (1usize..12).prop_flat_map(|width| vec(vec(prop::bool::weighted(0.2), width), 1..12))
+        ) {
+            let rows = rows
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|galaxy| if galaxy { Cell::Galaxy } else { Cell::Empty })
+                        .collect()
+                })
+                .collect();
+            let input = Grid::from_rows(rows).unwrap();
+            prop_assert_eq!(
+                distance_sum(&input, 2).unwrap(),
+                distance_sum_by_expanding(input).unwrap()
+            );
+        }
+    }
+}
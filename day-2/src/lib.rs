@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_core::{
+    answer::CheckedIterator,
+    parsing::{labelled, lines, tag, unsigned, ParseResult},
+    Generate, Solution,
+};
+use eyre::Result;
+use nom::{character::complete::alpha1, combinator::map, multi::separated_list0, sequence::tuple};
+use rand::{seq::SliceRandom, Rng};
+
+type Output = u32;
+type Input = Vec<Game>;
+
+/// The bag of part 1, which games have to fit in to be possible.
+pub const BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub hands: Vec<Hand>,
+}
+
+/// How many cubes of each color there are in a handful, or in a bag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Hand {
+    pub cubes: BTreeMap<String, u32>,
+}
+
+impl Hand {
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or_default()
+    }
+
+    /// Multiplies the counts of `colors`, which is 0 if any of them are
+    /// missing.
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> Result<Output> {
+        colors
+            .into_iter()
+            .map(|color| self.count(color))
+            .checked_product()
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Hand {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(cubes: I) -> Self {
+        let mut hand = Hand::default();
+        for (color, count) in cubes {
+            *hand.cubes.entry(color.into()).or_default() += count;
+        }
+        hand
+    }
+}
+
+impl Game {
+    /// Whether every hand could have been drawn from `bag`, which has none of
+    /// the colors it does not mention.
+    pub fn is_possible(&self, bag: &Hand) -> bool {
+        self.hands.iter().all(|hand| {
+            hand.cubes
+                .iter()
+                .all(|(color, &count)| count <= bag.count(color))
+        })
+    }
+
+    /// The fewest cubes of each color that the bag could have held.
+    pub fn fewest_cubes(&self) -> Hand {
+        let mut fewest = Hand::default();
+        for (color, &count) in self.hands.iter().flat_map(|hand| &hand.cubes) {
+            let most = fewest.cubes.entry(color.clone()).or_default();
+            *most = (*most).max(count);
+        }
+        fewest
+    }
+}
+
+/// Every color that any of the `games` shows.
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.hands)
+        .flat_map(|hand| hand.cubes.keys())
+        .map(String::as_str)
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 8;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 2286;
+
+    /// Returns a vector of lines from the input.
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        let parse_hand = map(
+            separated_list0(tag(", "), tuple((unsigned::<u32>, tag(" "), alpha1))),
+            |cubes| {
+                cubes
+                    .into_iter()
+                    .map(|(count, _, color)| (color, count))
+                    .collect()
+            },
+        );
+
+        let parse_line = map(
+            tuple((
+                labelled("Game", unsigned::<u32>),
+                tag(": "),
+                separated_list0(tag("; "), parse_hand),
+            )),
+            |(id, _, hands)| Game { id, hands },
+        );
+
+        lines(parse_line)(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        let bag = BAG.into_iter().collect();
+        input
+            .iter()
+            .filter(|game| game.is_possible(&bag))
+            .map(|game| game.id)
+            .checked_sum()
+    }
+
+    /// Multiplies the fewest cubes of every color in the input, so a game
+    /// that never shows one of them has a power of 0.
+    fn solve_part2(input: Input) -> Result<Output> {
+        let colors = colors(&input);
+        input
+            .iter()
+            .map(|game| game.fewest_cubes().power(colors.iter().copied()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .checked_sum()
+    }
+}
+
+impl Generate for Day2 {
+    /// Makes `size` games, each showing a few handfuls of up to 20 cubes of
+    /// each color.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        (1..=size)
+            .map(|id| {
+                let hands = (0..rng.gen_range(1..=6))
+                    .map(|_| {
+                        let colors = ["red", "green", "blue"];
+                        let count = rng.gen_range(1..=colors.len());
+                        colors
+                            .choose_multiple(rng, count)
+                            .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .collect::<Vec<_>>();
+                format!("Game {id}: {}", hands.join("; "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use crate::{Day2, Hand};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day2>().unwrap();
+    }
+
+    #[test]
+    fn any_colors() {
+        let input = "Game 1: 3 yellow, 4 red; 1 yellow, 2 purple\n\
+                     Game 2: 2 yellow, 1 yellow, 5 purple, 1 red\n\
+                     Game 3: 6 red";
+        let games = Day2::parse(input, ParseMode::Strict).unwrap();
+
+        assert_eq!(
+            games[1].hands[0],
+            [("yellow", 3), ("purple", 5), ("red", 1)]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(
+            games[0]
+                .fewest_cubes()
+                .power(["yellow", "red", "purple"])
+                .unwrap(),
+            24
+        );
+
+        let bag: Hand = [("yellow", 3), ("red", 5), ("purple", 5)]
+            .into_iter()
+            .collect();
+        assert!(games[0].is_possible(&bag));
+        assert!(games[1].is_possible(&bag));
+        assert!(!games[2].is_possible(&bag));
+        assert!(!games[0].is_possible(&[("yellow", 3), ("red", 5)].into_iter().collect()));
+
+        // game 3 has no yellow or purple cubes, so its power is 0
+        assert_eq!(Day2::solve_part2(games).unwrap(), 24 + 15);
+        // and only it fits in the bag, which has no yellow or purple cubes
+        let games = Day2::parse(input, ParseMode::Strict).unwrap();
+        assert_eq!(Day2::solve_part1(games).unwrap(), 3);
+    }
+}
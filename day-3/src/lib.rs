@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    answer::CheckedIterator,
+    grid::{grid, Grid, Position},
+    parsing::ParseResult,
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use rand::{seq::SliceRandom, Rng};
+
+type Output = u32;
+type Input = Grid<Cell>;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 4361;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 467835;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        grid(|c| {
+            Ok::<_, String>(match c {
+                '.' => Cell::Empty,
+                '0'..='9' => Cell::Digit(c.to_digit(10).unwrap()),
+                symbol => Cell::Symbol(symbol),
+            })
+        })(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        let is_engine_number = |number: &Number| {
+            number.positions().any(|position| {
+                input
+                    .neighbors8(position)
+                    .any(|neighbor| matches!(input[neighbor], Cell::Symbol(_)))
+            })
+        };
+
+        numbers(&input)?
+            .iter()
+            .filter(|number| is_engine_number(number))
+            .map(|number| number.value)
+            .checked_sum()
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        let numbers = numbers(&input)?;
+        // which number, if any, each cell is a digit of
+        let mut number_at = input.map(|_, _| None);
+        for (index, number) in numbers.iter().enumerate() {
+            for position in number.positions() {
+                number_at[position] = Some(index);
+            }
+        }
+
+        let mut gear_ratios = Vec::new();
+
+        for (position, &cell) in input.iter() {
+            if cell == Cell::Symbol('*') {
+                let adjacent_numbers = input
+                    .neighbors8(position)
+                    .filter_map(|neighbor| number_at[neighbor])
+                    .collect::<HashSet<_>>();
+
+                if adjacent_numbers.len() == 2 {
+                    let ratio = adjacent_numbers
+                        .iter()
+                        .map(|&index| numbers[index].value)
+                        .checked_product()?;
+                    gear_ratios.push(ratio);
+                }
+            }
+        }
+
+        gear_ratios.into_iter().checked_sum()
+    }
+}
+
+impl Generate for Day3 {
+    /// Makes a schematic `size` cells wide and high, scattered with numbers
+    /// of up to three digits and with symbols, many of which are gears.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut row = String::new();
+                while row.len() < size {
+                    let after_number = row.ends_with(|c: char| c.is_ascii_digit());
+                    match rng.gen_range(0..10) {
+                        0 | 1 if !after_number => {
+                            let digits = rng.gen_range(1..=3).min(size - row.len());
+                            let number = rng
+                                .gen_range(10u32.pow(digits as u32 - 1)..10u32.pow(digits as u32));
+                            row += &number.to_string();
+                        }
+                        2 => row.push(*b"*#+$/@%=&-".choose(rng).unwrap() as char),
+                        3 => row.push('*'),
+                        _ => row.push('.'),
+                    }
+                }
+                row
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Digit(u32),
+    Symbol(char),
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Number {
+    value: u32,
+    /// The position of the first digit.
+    start: Position,
+    length: usize,
+}
+
+impl Number {
+    fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        let (x, y) = self.start;
+        (x..x + self.length).map(move |x| (x, y))
+    }
+}
+
+/// Every number in the schematic, which is a run of digits within a row.
+fn numbers(input: &Input) -> Result<Vec<Number>> {
+    let mut numbers = Vec::new();
+
+    for (y, row) in input.rows().enumerate() {
+        let mut current: Option<Number> = None;
+
+        for (x, &cell) in row.iter().enumerate() {
+            match (cell, &mut current) {
+                (Cell::Digit(digit), Some(number)) => {
+                    number.value = number
+                        .value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit))
+                        .ok_or_else(|| {
+                            let (x, y) = number.start;
+                            eyre!("the number at ({x}, {y}) does not fit in a u32")
+                        })?;
+                    number.length += 1;
+                }
+                (Cell::Digit(digit), None) => {
+                    current = Some(Number {
+                        value: digit,
+                        start: (x, y),
+                        length: 1,
+                    });
+                }
+                _ => numbers.extend(current.take()),
+            }
+        }
+
+        numbers.extend(current);
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use crate::Day3;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day3>().unwrap();
+    }
+
+    #[test]
+    fn number_too_big() {
+        let input = Day3::parse("99999999999*", ParseMode::Strict).unwrap();
+        assert_eq!(
+            Day3::solve_part1(input).unwrap_err().to_string(),
+            "the number at (0, 0) does not fit in a u32"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    answer::CheckedIterator,
+    parsing::{labelled, lines, unsigned, unsigned_list, ParseResult},
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use nom::{combinator::map, sequence::tuple};
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng,
+};
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    winning_numbers: Vec<u32>,
+    has_numbers: Vec<u32>,
+}
+
+type Output = u32;
+type Input = Vec<Card>;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 13;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 30;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        lines(map(
+            tuple((
+                labelled("Card", unsigned::<u32>),
+                labelled(":", unsigned_list::<u32>),
+                labelled(" |", unsigned_list::<u32>),
+            )),
+            |(_, winning_numbers, has_numbers)| Card {
+                winning_numbers,
+                has_numbers,
+            },
+        ))(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        input
+            .iter()
+            .map(|card| {
+                let number_of_overlaps = count_overlaps(card);
+                if number_of_overlaps == 0 {
+                    Ok(0)
+                } else {
+                    2u32.checked_pow(number_of_overlaps - 1).ok_or_else(|| {
+                        eyre!("the points for {number_of_overlaps} matches do not fit in a u32")
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .checked_sum()
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        // how many of each card there are, counting the copies won so far
+        let mut copies = vec![1u32; input.len()];
+        for (index, card) in input.iter().enumerate() {
+            let won = copies[index];
+            let matches = count_overlaps(card) as usize;
+            let Some(next_cards) = copies.get_mut(index + 1..=index + matches) else {
+                return Err(eyre!(
+                    "card {} wins copies of cards past the end of the table",
+                    index + 1
+                ));
+            };
+            for (next_index, count) in (index + 1..).zip(next_cards) {
+                *count = count.checked_add(won).ok_or_else(|| {
+                    eyre!(
+                        "there are more than {} copies of card {}",
+                        u32::MAX,
+                        next_index + 1
+                    )
+                })?;
+            }
+        }
+
+        copies.into_iter().checked_sum()
+    }
+}
+
+/// How many copies of a generated card there can be at most, so that the
+/// total in part 2 fits.
+const MAX_COPIES: u32 = 10_000;
+
+impl Generate for Day4 {
+    /// Makes `size` cards with 10 winning numbers and 25 numbers each, where
+    /// no card wins copies of cards past the end of the table.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let mut copies = vec![1; size];
+        (0..size)
+            .map(|index| {
+                // win fewer cards when that would make too many copies
+                let won = copies[index];
+                let mut matches = rng.gen_range(0..=10).min(size - index - 1);
+                while copies[index + 1..=index + matches]
+                    .iter()
+                    .any(|&count| count + won > MAX_COPIES)
+                {
+                    matches -= 1;
+                }
+                for count in &mut copies[index + 1..=index + matches] {
+                    *count += won;
+                }
+
+                // the first 10 are the winning numbers, and the numbers the
+                // card has are the first few of them and 25 other numbers
+                let numbers = sample(rng, 99, 35).into_iter().map(|number| number + 1);
+                let numbers = numbers.collect::<Vec<_>>();
+                let winning = &numbers[..10];
+                let mut has = numbers[..matches]
+                    .iter()
+                    .chain(&numbers[10 + matches..])
+                    .copied()
+                    .collect::<Vec<_>>();
+                has.shuffle(rng);
+
+                let list = |numbers: &[usize]| {
+                    numbers
+                        .iter()
+                        .map(|number| format!("{number:>2}"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                };
+                format!("Card {:>3}: {} | {}", index + 1, list(winning), list(&has))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn count_overlaps(card: &Card) -> u32 {
+    let winning_numbers = card.winning_numbers.iter().collect::<HashSet<_>>();
+    let has_numbers = card.has_numbers.iter().collect::<HashSet<_>>();
+    winning_numbers.intersection(&has_numbers).count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use crate::Day4;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day4>().unwrap();
+    }
+
+    #[test]
+    fn too_many_copies() {
+        let input = Day4::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 4", ParseMode::Strict).unwrap();
+        assert_eq!(
+            Day4::solve_part2(input).unwrap_err().to_string(),
+            "card 1 wins copies of cards past the end of the table"
+        );
+
+        // every card wins a copy of every card after it, so the number of
+        // copies doubles with each card
+        let cards = (1..=33)
+            .map(|card| {
+                let numbers = |other| {
+                    let numbers = [other].into_iter().chain(card..33);
+                    numbers.map(|number| number.to_string()).collect::<Vec<_>>()
+                };
+                format!(
+                    "Card {card}: {} | {}",
+                    numbers(99).join(" "),
+                    numbers(98).join(" ")
+                )
+            })
+            .collect::<Vec<_>>();
+        let input = Day4::parse(&cards.join("\n"), ParseMode::Strict).unwrap();
+        assert_eq!(
+            Day4::solve_part2(input).unwrap_err().to_string(),
+            format!("there are more than {} copies of card 33", u32::MAX)
+        );
+    }
+}
@@ -0,0 +1,441 @@
+use std::ops::Range;
+
+use aoc_core::{
+    answer::checked_cast,
+    parsing::{blocks, labelled, lines, tag, unsigned_list, ParseResult},
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use nom::{
+    bytes::complete::is_not,
+    character::complete::newline,
+    combinator::map_res,
+    sequence::{separated_pair, terminated},
+};
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng,
+};
+
+type Output = u64;
+#[derive(Debug)]
+pub struct Input {
+    seeds: Vec<u64>,
+    seed_to_soil_map: Vec<Map>,
+    soil_to_fertilizer_map: Vec<Map>,
+    fertilizer_to_water_map: Vec<Map>,
+    water_to_light_map: Vec<Map>,
+    light_to_temperature_map: Vec<Map>,
+    temperature_to_humidity_map: Vec<Map>,
+    humidity_to_location_map: Vec<Map>,
+}
+
+/// The maps in the order they appear in the almanac.
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// One past the largest number in the almanac. Ranges of numbers are
+/// `u128`s, so that they can end here.
+const END: u128 = u64::MAX as u128 + 1;
+
+#[derive(Debug)]
+struct Map {
+    // first number
+    destination_range_start: u64,
+    // second number
+    source_range_start: u64,
+    // third number
+    range_length: u64,
+}
+
+impl Map {
+    /// The numbers the map moves, which the parser makes sure end by
+    /// [`END`].
+    fn source(&self) -> Range<u128> {
+        let start = u128::from(self.source_range_start);
+        start..start + u128::from(self.range_length)
+    }
+
+    /// Where the map moves `number`, which is in its source, or the end of
+    /// its source. This can be past the largest `u64`.
+    fn offset(&self, number: u128) -> u128 {
+        number - u128::from(self.source_range_start) + u128::from(self.destination_range_start)
+    }
+
+    fn past_the_end(&self) -> eyre::Report {
+        eyre!(
+            "the map from {} moves numbers past {}",
+            self.source_range_start,
+            u64::MAX
+        )
+    }
+}
+
+fn resolve_maps(maps: &[Map], x: u64) -> Result<u64> {
+    for map in maps {
+        if map.source().contains(&x.into()) {
+            return u64::try_from(map.offset(x.into())).map_err(|_| map.past_the_end());
+        }
+    }
+    Ok(x)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 35;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 46;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        let range = map_res(unsigned_list::<u64>, |numbers| match numbers[..] {
+            [destination_range_start, source_range_start, range_length] => {
+                let map = Map {
+                    destination_range_start,
+                    source_range_start,
+                    range_length,
+                };
+                if map.source().end > END {
+                    return Err(format!(
+                        "the {range_length} numbers from {source_range_start} go past {}",
+                        u64::MAX
+                    ));
+                }
+                Ok(map)
+            }
+            _ => Err(format!("expected 3 numbers, found {}", numbers.len())),
+        });
+        let map_block = separated_pair(
+            terminated(is_not(" \n"), tag(" map:")),
+            newline,
+            lines(range),
+        );
+
+        map_res(
+            separated_pair(
+                labelled("seeds:", unsigned_list::<u64>),
+                tag("\n\n"),
+                blocks(map_block),
+            ),
+            |(seeds, maps)| {
+                let names = maps.iter().map(|&(name, _)| name).collect::<Vec<_>>();
+                if names != MAP_NAMES {
+                    return Err(format!(
+                        "expected the {} maps, found {}",
+                        MAP_NAMES.join(", "),
+                        names.join(", ")
+                    ));
+                }
+
+                let mut maps = maps.into_iter().map(|(_, map)| map);
+                let mut next = || maps.next().unwrap();
+                Ok(Input {
+                    seeds,
+                    seed_to_soil_map: next(),
+                    soil_to_fertilizer_map: next(),
+                    fertilizer_to_water_map: next(),
+                    water_to_light_map: next(),
+                    light_to_temperature_map: next(),
+                    temperature_to_humidity_map: next(),
+                    humidity_to_location_map: next(),
+                })
+            },
+        )(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        input
+            .seeds
+            .iter()
+            .map(|&seed| input.location(seed))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .min()
+            .ok_or_else(|| eyre!("there are no seeds"))
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        let seeds = seed_ranges(&input)?;
+        input
+            .locations(seeds)?
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .ok_or_else(|| eyre!("there are no seeds"))
+            .and_then(checked_cast)
+    }
+}
+
+impl Input {
+    fn maps(&self) -> [&[Map]; 7] {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temperature_to_humidity_map,
+            &self.humidity_to_location_map,
+        ]
+    }
+
+    /// The location a seed needs, found by going through every map.
+    fn location(&self, seed: u64) -> Result<u64> {
+        self.maps()
+            .into_iter()
+            .try_fold(seed, |number, maps| resolve_maps(maps, number))
+    }
+
+    /// The locations every seed in `seeds` needs, going through the maps a
+    /// whole range of numbers at a time.
+    fn locations(&self, seeds: Vec<Range<u128>>) -> Result<Vec<Range<u128>>> {
+        self.maps()
+            .into_iter()
+            .try_fold(seeds, |ranges, maps| resolve_ranges(maps, ranges))
+    }
+}
+
+/// The ranges of seeds in part 2, where the seeds are pairs of a start and a
+/// length.
+fn seed_ranges(input: &Input) -> Result<Vec<Range<u128>>> {
+    input
+        .seeds
+        .chunks(2)
+        .map(|chunk| match *chunk {
+            [start, length] => {
+                let range = u128::from(start)..u128::from(start) + u128::from(length);
+                if range.end > END {
+                    return Err(eyre!(
+                        "the {length} seeds from {start} go past {}",
+                        u64::MAX
+                    ));
+                }
+                Ok(range)
+            }
+            _ => Err(eyre!("the seeds do not come in pairs")),
+        })
+        .collect()
+}
+
+/// Where every number in `ranges` ends up through `maps`, as ranges. Like
+/// [`resolve_maps`], the first map that a number is in wins.
+fn resolve_ranges(maps: &[Map], ranges: Vec<Range<u128>>) -> Result<Vec<Range<u128>>> {
+    let mut unmapped = ranges
+        .into_iter()
+        .filter(|range| !range.is_empty())
+        .collect::<Vec<_>>();
+    let mut mapped = Vec::new();
+
+    for map in maps {
+        let source = map.source();
+        let mut rest = Vec::new();
+        for range in unmapped {
+            let overlap = range.start.max(source.start)..range.end.min(source.end);
+            if overlap.is_empty() {
+                rest.push(range);
+                continue;
+            }
+
+            let destination = map.offset(overlap.start)..map.offset(overlap.end);
+            if destination.end > END {
+                return Err(map.past_the_end());
+            }
+            mapped.push(destination);
+            rest.extend([range.start..overlap.start, overlap.end..range.end]);
+        }
+        unmapped = rest.into_iter().filter(|range| !range.is_empty()).collect();
+    }
+
+    mapped.extend(unmapped);
+    Ok(mapped)
+}
+
+/// The answer to part 2 the slow way, by going through the maps one seed at
+/// a time.
+#[cfg(test)]
+fn lowest_location_by_seed(input: &Input) -> Option<u64> {
+    let seeds = seed_ranges(input).ok()?.into_iter().flatten();
+    seeds
+        .map(|seed| input.location(seed.try_into().ok()?).ok())
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .min()
+}
+
+impl Generate for Day5 {
+    /// Makes an almanac with `size` ranges of seeds, each map of which moves
+    /// up to `size` ranges of the numbers below `100 * size` around.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let size = size.max(1);
+        let limit = 100 * size;
+
+        let seeds = (0..size)
+            .map(|_| format!("{} {}", rng.gen_range(0..limit), rng.gen_range(1..=100)))
+            .collect::<Vec<_>>();
+        let mut almanac = format!("seeds: {}", seeds.join(" "));
+
+        for name in MAP_NAMES {
+            // cut the numbers into ranges, and move some of them to where
+            // others were, so that no two ranges overlap
+            let count = rng.gen_range(0..size);
+            let mut cuts = sample(rng, limit - 1, count)
+                .into_iter()
+                .map(|cut| cut + 1)
+                .collect::<Vec<_>>();
+            cuts.sort_unstable();
+            let starts = [0].into_iter().chain(cuts.iter().copied());
+            let ends = cuts.iter().copied().chain([limit]);
+            let mut ranges = starts.zip(ends).collect::<Vec<_>>();
+
+            ranges.shuffle(rng);
+            let mut destination = 0;
+            let mut lines = ranges
+                .into_iter()
+                .map(|(start, end)| {
+                    let line = format!("{destination} {start} {}", end - start);
+                    destination += end - start;
+                    line
+                })
+                .collect::<Vec<_>>();
+            lines.shuffle(rng);
+            lines.truncate(rng.gen_range(1..=lines.len()));
+
+            almanac += &format!("\n\n{name} map:\n{}", lines.join("\n"));
+        }
+
+        almanac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use proptest::{collection::vec, prelude::*};
+
+    use crate::{lowest_location_by_seed, resolve_maps, Day5, Input, Map, END, MAP_NAMES};
+
+    #[test]
+    fn map_resolve() {
+        let maps = vec![
+            Map {
+                destination_range_start: 50,
+                source_range_start: 98,
+                range_length: 2,
+            },
+            Map {
+                destination_range_start: 52,
+                source_range_start: 50,
+                range_length: 48,
+            },
+        ];
+
+        let resolve = |x| resolve_maps(&maps, x).unwrap();
+        assert_eq!(resolve(0), 0);
+        assert_eq!(resolve(1), 1);
+        assert_eq!(resolve(48), 48);
+        assert_eq!(resolve(50), 52);
+        assert_eq!(resolve(51), 53);
+        assert_eq!(resolve(96), 98);
+        assert_eq!(resolve(97), 99);
+        assert_eq!(resolve(98), 50);
+        assert_eq!(resolve(99), 51);
+    }
+
+    #[test]
+    fn past_the_largest_number() {
+        let almanac = |seeds: &str, map: &str| {
+            let maps = MAP_NAMES.map(|name| format!("{name} map:\n{map}"));
+            let almanac = format!("seeds: {seeds}\n\n{}", maps.join("\n\n"));
+            Day5::parse(&almanac, ParseMode::Strict).unwrap()
+        };
+
+        let map = format!("0 {} 2", u64::MAX);
+        let maps = MAP_NAMES.map(|name| format!("{name} map:\n{map}"));
+        assert!(Day5::parse(
+            &format!("seeds: 5 1\n\n{}", maps.join("\n\n")),
+            ParseMode::Strict
+        )
+        .is_err());
+
+        // ranges that end at the largest number
+        let map = format!("{} 0 6", u64::MAX - 5);
+        assert_eq!(Day5::solve_part1(almanac("5", &map)).unwrap(), u64::MAX);
+        assert_eq!(
+            Day5::solve_part2(almanac("0 6", &map)).unwrap(),
+            u64::MAX - 5
+        );
+        let map = format!("0 {} 1", u64::MAX);
+        let seeds = format!("{} 1", u64::MAX);
+        assert_eq!(Day5::solve_part2(almanac(&seeds, &map)).unwrap(), 0);
+
+        let map = format!("{} 0 10", u64::MAX - 5);
+        assert!(Day5::solve_part1(almanac("7", &map)).is_err());
+        assert_eq!(Day5::solve_part1(almanac("4", &map)).unwrap(), u64::MAX - 1);
+        assert!(Day5::solve_part2(almanac("4 3", &map)).is_err());
+
+        let seeds = format!("{} 2", u64::MAX);
+        assert_eq!(
+            Day5::solve_part2(almanac(&seeds, "0 0 1"))
+                .unwrap_err()
+                .to_string(),
+            format!("the 2 seeds from {} go past {}", u64::MAX, u64::MAX)
+        );
+    }
+
+    #[test]
+    fn examples() {
+        check_examples::<Day5>().unwrap();
+    }
+
+    /// A number near either end of the numbers.
+    fn number() -> impl Strategy<Value = u64> {
+        prop_oneof![0u64..200, u64::MAX - 200..=u64::MAX]
+    }
+
+    fn maps() -> impl Strategy<Value = Vec<Map>> {
+        let map = (number(), number(), 0u64..50).prop_map(
+            |(destination_range_start, source_range_start, range_length)| Map {
+                destination_range_start,
+                source_range_start,
+                // the parser does not let a map go past the largest number
+                range_length: u64::try_from(END - u128::from(source_range_start))
+                    .map_or(range_length, |room| range_length.min(room)),
+            },
+        );
+        vec(map, 0..5)
+    }
+
+    proptest! {
+        #[test]
+        fn ranges_match_seeds(
+            seeds in vec((number(), 0u64..50), 1..4),
+            maps in [maps(), maps(), maps(), maps(), maps(), maps(), maps()],
+        ) {
+            let [a, b, c, d, e, f, g] = maps;
+            let input = Input {
+                seeds: seeds.into_iter().flat_map(|(start, length)| [start, length]).collect(),
+                seed_to_soil_map: a,
+                soil_to_fertilizer_map: b,
+                fertilizer_to_water_map: c,
+                water_to_light_map: d,
+                light_to_temperature_map: e,
+                temperature_to_humidity_map: f,
+                humidity_to_location_map: g,
+            };
+            let expected = lowest_location_by_seed(&input);
+            prop_assert_eq!(Day5::solve_part2(input).ok(), expected);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use aoc_core::{
+    answer::CheckedIterator,
+    parsing::{labelled, unsigned_list, ParseResult},
+    Generate, Solution,
+};
+use eyre::Result;
+use nom::{character::complete::newline, combinator::map, sequence::separated_pair};
+use rand::Rng;
+
+type Output = u64;
+type Input = Vec<Race>;
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    record: u64,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 288;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 71503;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        map(
+            separated_pair(
+                labelled("Time:", unsigned_list::<u64>),
+                newline,
+                labelled("Distance:", unsigned_list::<u64>),
+            ),
+            |(times, distances)| {
+                times
+                    .iter()
+                    .cloned()
+                    .zip(distances)
+                    .map(|(time, distance)| Race {
+                        time,
+                        record: distance,
+                    })
+                    .collect()
+            },
+        )(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        input.iter().map(ways_to_win).checked_product()
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        // the spaces between the numbers are just bad kerning, so there is
+        // really only one race
+        let time = input
+            .iter()
+            .map(|race| race.time.to_string())
+            .collect::<String>()
+            .parse()?;
+        let record = input
+            .iter()
+            .map(|race| race.record.to_string())
+            .collect::<String>()
+            .parse()?;
+
+        Self::solve_part1(vec![Race { time, record }])
+    }
+}
+
+/// How many ways there are to beat the record, which are the hold times `h`
+/// with `h * (time - h) > record`. Those are the ones between the roots of
+/// `h^2 - time * h + record`, which are found with an integer square root and
+/// nudged onto the exact boundary.
+fn ways_to_win(race: &Race) -> u64 {
+    let (time, record) = (u128::from(race.time), u128::from(race.record));
+    let distance = |hold: u128| hold * (time - hold);
+    let Some(discriminant) = (time * time).checked_sub(4 * record) else {
+        return 0;
+    };
+
+    // the shortest hold that wins, if any does
+    let mut shortest = (time - discriminant.isqrt()) / 2;
+    while shortest > 0 && distance(shortest - 1) > record {
+        shortest -= 1;
+    }
+    while shortest <= time / 2 && distance(shortest) <= record {
+        shortest += 1;
+    }
+    if shortest > time / 2 {
+        return 0;
+    }
+
+    // the longest hold that wins is as far from the end as the shortest is
+    // from the start
+    (time - 2 * shortest + 1) as u64
+}
+
+/// [`ways_to_win`] the slow way, by trying every hold time.
+#[cfg(test)]
+fn ways_to_win_by_trying(race: &Race) -> u64 {
+    (1..race.time)
+        .filter(|hold_time| {
+            let speed = hold_time;
+            let time = race.time - hold_time;
+            // a distance too big for a u64 beats any record
+            speed
+                .checked_mul(time)
+                .is_none_or(|distance| distance > race.record)
+        })
+        .count() as u64
+}
+
+impl Generate for Day6 {
+    /// Makes `size` races of up to 99 milliseconds, each of which can be
+    /// won. Part 2 reads all of the races as one, and its numbers no longer
+    /// fit in a u64 for more than 4 races.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let races = (0..size)
+            .map(|_| {
+                let time = rng.gen_range(5..=99);
+                let best = (time / 2) * (time - time / 2);
+                (time, rng.gen_range(0..best))
+            })
+            .collect::<Vec<(u64, u64)>>();
+
+        let times = races.iter().map(|(time, _)| format!("{time:>5}"));
+        let records = races.iter().map(|(_, record)| format!("{record:>5}"));
+        format!(
+            "Time:    {}\nDistance:{}",
+            times.collect::<String>(),
+            records.collect::<String>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::check_examples;
+
+    use proptest::prelude::*;
+
+    use crate::{ways_to_win, ways_to_win_by_trying, Day6, Race};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day6>().unwrap();
+    }
+
+    proptest! {
+        #[test]
+        fn ways_to_win_matches_trying(
+            (time, record) in (0u64..5000).prop_flat_map(|time| (Just(time), 0..=time * time / 4 + 1))
+        ) {
+            let race = Race { time, record };
+            prop_assert_eq!(ways_to_win(&race), ways_to_win_by_trying(&race));
+        }
+    }
+
+    #[test]
+    fn ways_to_win_big_races() {
+        let race = Race {
+            time: u64::MAX,
+            record: u64::MAX,
+        };
+        // every hold time but 1 and u64::MAX - 1 goes further than u64::MAX
+        assert_eq!(ways_to_win(&race), u64::MAX - 3);
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{
+    answer::{checked_cast, CheckedIterator},
+    parsing::{lines, mapped_char, tag, unsigned, ParseResult},
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use nom::{combinator::map, multi::count, sequence::tuple};
+use rand::{seq::SliceRandom, Rng};
+use tracing::trace;
+
+type Output = u64;
+type Input = Vec<Game>;
+
+#[derive(Debug)]
+pub struct Game {
+    hand: Vec<Card>,
+    bid: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(u8)]
+enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    pub fn from_char(letter: char) -> Result<Self, String> {
+        match letter {
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Eight),
+            '9' => Ok(Self::Nine),
+            'T' => Ok(Self::Ten),
+            'J' => Ok(Self::Jack),
+            'Q' => Ok(Self::Queen),
+            'K' => Ok(Self::King),
+            'A' => Ok(Self::Ace),
+            _ => Err(format!("{letter:?} is not a card")),
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 6440;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 5905;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        lines(map(
+            tuple((
+                count(mapped_char(Card::from_char), 5),
+                tag(" "),
+                unsigned::<u64>,
+            )),
+            |(cards, _, bid)| Game { hand: cards, bid },
+        ))(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        total_winnings(input, hand_type)
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        let games = input
+            .into_iter()
+            // in part 2, the jacks are actually jokers
+            .map(|game| Game {
+                hand: game
+                    .hand
+                    .into_iter()
+                    .map(|card| {
+                        if card == Card::Jack {
+                            Card::Joker
+                        } else {
+                            card
+                        }
+                    })
+                    .collect(),
+                ..game
+            })
+            .collect();
+        total_winnings(games, hand_type_with_jokers)
+    }
+}
+
+impl Generate for Day7 {
+    /// Makes `size` different hands, each with a bid of up to 1000. Cards
+    /// are drawn from a few of the labels, so that there are hands of every
+    /// type.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let size = size.min(13usize.pow(5));
+        let mut hands = HashSet::new();
+        let mut games = Vec::new();
+        while games.len() < size {
+            let count = rng.gen_range(1..=5);
+            let labels = b"23456789TJQKA".choose_multiple(rng, count);
+            let labels = labels.copied().collect::<Vec<_>>();
+            let hand = (0..5)
+                .map(|_| *labels.choose(rng).unwrap() as char)
+                .collect::<String>();
+            if hands.insert(hand.clone()) {
+                games.push(format!("{hand} {}", rng.gen_range(1..=1000)));
+            }
+        }
+        games.join("\n")
+    }
+}
+
+/// What a game wins, given its rank starting from 0.
+fn winnings(rank: usize, game: &Game) -> Result<u64> {
+    let rank = checked_cast::<usize, u64>(rank)? + 1;
+    rank.checked_mul(game.bid)
+        .ok_or_else(|| eyre!("the winnings of rank {rank} do not fit in a u64"))
+}
+
+/// The kinds of hands, from the weakest to the strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// Ranks the games by the type of their hands, and then by their cards in
+/// order, and adds up what they win.
+fn total_winnings(games: Vec<Game>, hand_type: fn(&[Card]) -> HandType) -> Result<Output> {
+    let mut games = games
+        .into_iter()
+        .map(|game| (hand_type(&game.hand), game))
+        .collect::<Vec<_>>();
+    games.sort_by(|(a_type, a), (b_type, b)| a_type.cmp(b_type).then_with(|| a.hand.cmp(&b.hand)));
+
+    games
+        .iter()
+        .enumerate()
+        .inspect(|(rank, game)| trace!("{rank}: {game:?}"))
+        .map(|(rank, (_, game))| winnings(rank, game))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .checked_sum()
+}
+
+fn hand_type(hand: &[Card]) -> HandType {
+    // how many there are of each card, the most first
+    let mut counts = hand
+        .iter()
+        .fold(HashMap::new(), |mut acc, card| {
+            *acc.entry(card).or_insert(0) += 1;
+            acc
+        })
+        .into_values()
+        .collect::<Vec<u32>>();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    match counts[..] {
+        [5, ..] => HandType::FiveOfAKind,
+        [4, ..] => HandType::FourOfAKind,
+        [3, 2, ..] => HandType::FullHouse,
+        [3, ..] => HandType::ThreeOfAKind,
+        [2, 2, ..] => HandType::TwoPair,
+        [2, ..] => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+/// The type of the hand with its jokers standing in for its most common
+/// other card, which is the best they can do.
+fn hand_type_with_jokers(hand: &[Card]) -> HandType {
+    let counter = hand.iter().fold(HashMap::new(), |mut acc, card| {
+        *acc.entry(card).or_insert(0) += 1;
+        acc
+    });
+    let most_frequent = counter
+        .iter()
+        .filter(|(&&card_type, _)| card_type != Card::Joker)
+        .max_by(|(_, &a), (_, &b)| a.cmp(&b))
+        .map(|(&&card_type, _)| card_type);
+    trace!("most frequent: {:?}", most_frequent);
+
+    let Some(most_frequent) = most_frequent else {
+        // every card is a joker
+        return hand_type(hand);
+    };
+    let hand = hand
+        .iter()
+        .map(|&card| {
+            if card == Card::Joker {
+                most_frequent
+            } else {
+                card
+            }
+        })
+        .collect::<Vec<_>>();
+    hand_type(&hand)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, Solution};
+
+    use crate::{hand_type, hand_type_with_jokers, Card, Day7, HandType};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day7>().unwrap();
+    }
+
+    /// In part 1 "J" is a jack, which beats a ten, rather than a joker,
+    /// which loses to every other card.
+    #[test]
+    fn jacks_outrank_tens() {
+        let input = Day7::parse_input("JJ234 1\nTT234 2").unwrap().1;
+        // as a joker, the jack's hand would be the weaker one, making it 5
+        assert_eq!(Day7::solve_part1(input).unwrap(), 4);
+    }
+
+    #[test]
+    fn hand_types() {
+        let hand = |cards: &str| {
+            let cards = cards.chars().map(|card| Card::from_char(card).unwrap());
+            cards.collect::<Vec<_>>()
+        };
+        for (cards, expected) in [
+            ("AAAAA", HandType::FiveOfAKind),
+            ("AA8AA", HandType::FourOfAKind),
+            ("23332", HandType::FullHouse),
+            ("TTT98", HandType::ThreeOfAKind),
+            ("23432", HandType::TwoPair),
+            ("A23A4", HandType::OnePair),
+            ("23456", HandType::HighCard),
+        ] {
+            assert_eq!(hand_type(&hand(cards)), expected, "{cards}");
+        }
+
+        let jokers = |cards: &str| {
+            let cards = hand(cards).into_iter();
+            let cards = cards.map(|card| {
+                if card == Card::Jack {
+                    Card::Joker
+                } else {
+                    card
+                }
+            });
+            hand_type_with_jokers(&cards.collect::<Vec<_>>())
+        };
+        assert_eq!(jokers("QJJQ2"), HandType::FourOfAKind);
+        assert_eq!(jokers("JJJJJ"), HandType::FiveOfAKind);
+        assert_eq!(jokers("2345J"), HandType::OnePair);
+    }
+}
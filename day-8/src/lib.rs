@@ -0,0 +1,256 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use aoc_core::{
+    answer::checked_lcm,
+    parsing::{lines, mapped_char, tag, ParseResult},
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use nom::{
+    combinator::map,
+    multi::{count, many1},
+    sequence::tuple,
+};
+use rand::{seq::SliceRandom, Rng};
+use tracing::debug;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LeftRight {
+    Left,
+    Right,
+}
+
+type ThreeChars = [u8; 3];
+const Z_AS_U8: u8 = b'Z';
+#[derive(Debug)]
+struct Node {
+    pub name: ThreeChars,
+    pub left: ThreeChars,
+    pub right: ThreeChars,
+}
+
+type Output = u64;
+#[derive(Debug)]
+pub struct Input {
+    sequence: Vec<LeftRight>,
+    nodes: Vec<Node>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 6;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 6;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        let parse_direction = || {
+            mapped_char(|c| match c {
+                'L' => Ok(LeftRight::Left),
+                'R' => Ok(LeftRight::Right),
+                _ => Err(format!("{c:?} is not a direction")),
+            })
+        };
+
+        let parse_three_letters = || {
+            let letter = mapped_char(|c: char| {
+                if c.is_ascii_alphanumeric() {
+                    Ok(c as u8)
+                } else {
+                    Err(format!("{c:?} is not an ASCII letter or digit"))
+                }
+            });
+            map(count(letter, 3), |letters| {
+                [letters[0], letters[1], letters[2]]
+            })
+        };
+
+        map(
+            tuple((
+                many1(parse_direction()),
+                tag("\n\n"),
+                lines(map(
+                    tuple((
+                        parse_three_letters(),
+                        tag(" = ("),
+                        parse_three_letters(),
+                        tag(", "),
+                        parse_three_letters(),
+                        tag(")"),
+                    )),
+                    |(name, _, left, _, right, _)| Node { name, left, right },
+                )),
+            )),
+            |(sequence, _, nodes)| Input { sequence, nodes },
+        )(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        let nodes = input.nodes.iter().fold(HashMap::new(), |mut acc, node| {
+            acc.insert(node.name, node);
+            acc
+        });
+
+        let mut visited = 0;
+        let mut current_node = [b'A', b'A', b'A'];
+
+        for direction in input.sequence.iter().cloned().cycle() {
+            let node = find_node(&nodes, &current_node)?;
+            current_node = match direction {
+                LeftRight::Left => node.left,
+                LeftRight::Right => node.right,
+            };
+
+            visited += 1;
+
+            if current_node[0] == Z_AS_U8
+                && current_node[1] == Z_AS_U8
+                && current_node[2] == Z_AS_U8
+            {
+                break;
+            }
+        }
+
+        Ok(visited)
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        let nodes = input.nodes.iter().fold(HashMap::new(), |mut acc, node| {
+            acc.insert(node.name, node);
+            acc
+        });
+
+        let current_nodes = input
+            .nodes
+            .iter()
+            .filter(|node| node.name[2] == b'A')
+            .map(|node| node.name)
+            .collect::<Vec<_>>();
+
+        let mut distances = current_nodes.iter().map(|node| {
+            let mut count = 0;
+            let mut current = node;
+
+            for direction in input.sequence.iter().cloned().cycle() {
+                count += 1;
+                let node = find_node(&nodes, current)?;
+                current = match direction {
+                    LeftRight::Left => &node.left,
+                    LeftRight::Right => &node.right,
+                };
+                if current[2] == Z_AS_U8 {
+                    break;
+                }
+            }
+
+            Ok(count)
+        });
+
+        debug!("current_nodes: {:?}", current_nodes.len());
+
+        distances.try_fold(1, |lcm, distance: Result<_>| checked_lcm(lcm, distance?))
+    }
+}
+
+fn find_node<'a>(nodes: &HashMap<ThreeChars, &'a Node>, name: &ThreeChars) -> Result<&'a Node> {
+    nodes.get(name).copied().ok_or_else(|| {
+        eyre!(
+            "there is no node {}",
+            String::from_utf8_lossy(name.as_slice())
+        )
+    })
+}
+
+impl Generate for Day8 {
+    /// Makes a network of about `size` nodes, made up of a path from `AAA` to
+    /// `ZZZ` and up to 5 more from a node ending in `A` to one ending in `Z`.
+    /// Every node of a path leads further along it whichever way is taken,
+    /// so that every path reaches its end.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let paths = rng.gen_range(1..=(size / 2).clamp(1, 6));
+        let mut lengths = vec![2; paths];
+        for _ in 2 * paths..size {
+            lengths[rng.gen_range(0..paths)] += 1;
+        }
+
+        let mut names = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+        let mut name = |rng: &mut R, last: RangeInclusive<char>| loop {
+            let name = [
+                rng.gen_range('A'..='Z'),
+                rng.gen_range('A'..='Z'),
+                rng.gen_range(last.clone()),
+            ];
+            let name = name.into_iter().collect::<String>();
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        let mut nodes = Vec::new();
+        for (index, &length) in lengths.iter().enumerate() {
+            let path = (0..length)
+                .map(|step| match step {
+                    0 if index == 0 => "AAA".to_string(),
+                    _ if index == 0 && step == length - 1 => "ZZZ".to_string(),
+                    0 => name(rng, 'A'..='A'),
+                    _ if step == length - 1 => name(rng, 'Z'..='Z'),
+                    _ => name(rng, 'B'..='Y'),
+                })
+                .collect::<Vec<_>>();
+
+            for (step, node) in path.iter().enumerate() {
+                // the end leads back into the path, like the real networks
+                let next = match step + 1 {
+                    next if next < length => next..length,
+                    _ => 1..length,
+                };
+                let left = &path[rng.gen_range(next.clone())];
+                let right = &path[rng.gen_range(next)];
+                nodes.push(format!("{node} = ({left}, {right})"));
+            }
+        }
+        nodes.shuffle(rng);
+
+        let directions = (0..rng.gen_range(1..=size.max(1)))
+            .map(|_| if rng.gen() { 'L' } else { 'R' })
+            .collect::<String>();
+        format!("{directions}\n\n{}", nodes.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use crate::Day8;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day8>().unwrap();
+    }
+
+    #[test]
+    fn bad_nodes() {
+        let input = Day8::parse("L\n\nAAA = (BBZ, AAA)", ParseMode::Strict).unwrap();
+        assert_eq!(
+            Day8::solve_part1(input).unwrap_err().to_string(),
+            "there is no node BBZ"
+        );
+        let input = Day8::parse("R\n\nAAA = (AAA, BBB)", ParseMode::Strict).unwrap();
+        assert_eq!(
+            Day8::solve_part2(input).unwrap_err().to_string(),
+            "there is no node BBB"
+        );
+
+        let error =
+            Day8::parse("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZÉ, ZZZ)", ParseMode::Strict).unwrap_err();
+        assert!(format!("{error:#}").contains("'É' is not an ASCII letter or digit"));
+    }
+}
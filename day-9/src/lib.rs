@@ -0,0 +1,160 @@
+use aoc_core::{
+    answer::CheckedIterator,
+    parsing::{lines, signed_list, ParseResult},
+    Generate, Solution,
+};
+use eyre::{eyre, Result};
+use rand::Rng;
+
+type Output = i64;
+type Input = Vec<Vec<i64>>;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 114;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 2;
+
+    fn parse_input(input: &str) -> ParseResult<'_, Input> {
+        lines(signed_list::<i64>)(input)
+    }
+
+    fn solve_part1(input: Input) -> Result<Output> {
+        input
+            .into_iter()
+            .map(|line| next_value(&find_history(line)?))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .checked_sum()
+    }
+
+    fn solve_part2(input: Input) -> Result<Output> {
+        input
+            .into_iter()
+            .map(|line| previous_value(&find_history(line)?))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .checked_sum()
+    }
+}
+
+impl Generate for Day9 {
+    /// Makes `size` histories, each the values of a polynomial of up to
+    /// degree 4, with enough values that its differences become constant.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let degree = rng.gen_range(0..=4);
+                let coefficients = (0..=degree)
+                    .map(|_| rng.gen_range(-5..=5))
+                    .collect::<Vec<i64>>();
+                let length = rng.gen_range(degree + 2..=21);
+                (0..length)
+                    .map(|x| {
+                        // Horner's method, from the highest power down
+                        let value = coefficients.iter().rev().fold(0, |value, c| value * x + c);
+                        value.to_string()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn calculate_differences(numbers: &[i64]) -> Result<Vec<i64>> {
+    numbers
+        .windows(2)
+        .map(|w| {
+            w[1].checked_sub(w[0]).ok_or_else(|| {
+                eyre!(
+                    "the difference between {} and {} does not fit in an i64",
+                    w[0],
+                    w[1]
+                )
+            })
+        })
+        .collect()
+}
+
+/// The line and its differences, down to the first differences that are all
+/// the same. Every one of them has at least one value.
+fn find_history(line: Vec<i64>) -> Result<Vec<Vec<i64>>> {
+    if line.len() < 2 {
+        return Err(eyre!(
+            "the history {line:?} has only one value, so it cannot be extrapolated"
+        ));
+    }
+    let mut differences = calculate_differences(&line)?;
+    let mut history = vec![line, differences.clone()];
+
+    // while the differences are not all the same, keep
+    // calculating and pushing to the history
+    while !differences.iter().all(|&x| x == differences[0]) {
+        differences = calculate_differences(&differences)?;
+        history.push(differences.clone());
+    }
+
+    Ok(history)
+}
+
+/// The value after the last one of the history's line.
+fn next_value(history: &[Vec<i64>]) -> Result<i64> {
+    history.iter().rev().try_fold(0, |difference: i64, line| {
+        difference
+            .checked_add(line[line.len() - 1])
+            .ok_or_else(|| eyre!("the next value of {:?} does not fit in an i64", history[0]))
+    })
+}
+
+/// The value before the first one of the history's line.
+fn previous_value(history: &[Vec<i64>]) -> Result<i64> {
+    history.iter().rev().try_fold(0, |difference: i64, line| {
+        line[0].checked_sub(difference).ok_or_else(|| {
+            eyre!(
+                "the previous value of {:?} does not fit in an i64",
+                history[0]
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{check_examples, parsing::ParseMode, Solution};
+
+    use crate::Day9;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day9>().unwrap();
+    }
+
+    #[test]
+    fn bad_histories() {
+        let input = Day9::parse("1 2 3\n7", ParseMode::Strict).unwrap();
+        assert_eq!(
+            Day9::solve_part1(input).unwrap_err().to_string(),
+            "the history [7] has only one value, so it cannot be extrapolated"
+        );
+
+        let input = Day9::parse(&format!("{} 0", i64::MIN), ParseMode::Strict).unwrap();
+        assert!(Day9::solve_part1(input).is_err());
+        let input = Day9::parse(&format!("0 {}", i64::MAX), ParseMode::Strict).unwrap();
+        assert!(Day9::solve_part1(input).is_err());
+        let input = Day9::parse(
+            &format!("{} {}", i64::MIN + 1, i64::MIN + 1),
+            ParseMode::Strict,
+        )
+        .unwrap();
+        assert!(Day9::solve_part2(input).is_ok());
+        let input = Day9::parse(&format!("{} {}", i64::MIN + 1, 0), ParseMode::Strict).unwrap();
+        assert!(Day9::solve_part2(input).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_core::{parsing::ParseResult, Generate, Solution, Unsolved};
+use eyre::Result;
+use rand::Rng;
+
+type Output = u64;
+pub struct Input {}
+
+pub struct DayN;
+
+impl Solution for DayN {
+    const DAY: u8 = 0;
+
+    type Input = Input;
+    type Output = Output;
+
+    const PART_1_EXPECTED_TEST_OUTPUT: Output = 0;
+    const PART_2_EXPECTED_TEST_OUTPUT: Output = 0;
+
+    fn parse_input(_input: &str) -> ParseResult<'_, Input> {
+        Ok(("", Input {}))
+    }
+
+    fn solve_part1(_input: Input) -> Result<Output> {
+        Err(Unsolved.into())
+    }
+
+    fn solve_part2(_input: Input) -> Result<Output> {
+        Err(Unsolved.into())
+    }
+}
+
+impl Generate for DayN {
+    fn generate<R: Rng + ?Sized>(_rng: &mut R, _size: usize) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::check_examples;
+
+    use crate::DayN;
+
+    #[test]
+    fn examples() {
+        check_examples::<DayN>().unwrap();
+    }
+}